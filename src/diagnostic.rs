@@ -0,0 +1,29 @@
+use std::fmt;
+use super::schema_error::SchemaError;
+
+/// Замечание, из-за которого SieveModel не прошла нормализацию
+#[derive(Debug, Clone, PartialEq)]
+pub enum Diagnostic {
+    /// Терм не соответствует схеме
+    Schema(SchemaError),
+
+    /// Поле для однозначной сортировки отсутствует в схеме
+    UnknownTiebreaker(String),
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Diagnostic::Schema(error) => write!(f, "{}", error),
+            Diagnostic::UnknownTiebreaker(field) => write!(f, "Неизвестное поле для однозначной сортировки: {}", field),
+        }
+    }
+}
+
+impl std::error::Error for Diagnostic {}
+
+impl From<SchemaError> for Diagnostic {
+    fn from(error: SchemaError) -> Self {
+        Diagnostic::Schema(error)
+    }
+}
@@ -0,0 +1,41 @@
+use super::filter_operator::FilterOperator;
+
+/// Тип поля в схеме, определяющий допустимые операторы
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FieldType {
+    /// Строка: поддерживаются все операторы
+    Text,
+
+    /// Число: поддерживаются равенство и сравнения
+    Number,
+
+    /// Дата и время: поддерживаются равенство и сравнения
+    Date,
+
+    /// Логическое значение: поддерживаются только `==` и `!=`
+    Bool,
+}
+
+/// Реализация методов для FieldType
+impl FieldType {
+    /// Проверяет, можно ли применять оператор к полю этого типа
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use sieve::{FieldType, FilterOperator};
+    /// assert!(FieldType::Text.supports(&FilterOperator::Contains));
+    /// assert!(FieldType::Number.supports(&FilterOperator::GreaterThan));
+    /// assert!(!FieldType::Number.supports(&FilterOperator::Contains));
+    /// assert!(!FieldType::Bool.supports(&FilterOperator::LessThan));
+    /// ```
+    pub fn supports(&self, operator: &FilterOperator) -> bool {
+        match self {
+            FieldType::Text => true,
+            FieldType::Number | FieldType::Date => !matches!(
+                operator,
+                FilterOperator::Contains | FilterOperator::StartsWith | FilterOperator::EndsWith
+            ),
+            FieldType::Bool => matches!(operator, FilterOperator::Equals | FilterOperator::NotEquals),
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use std::cmp::Ordering;
+use std::fmt;
+use super::sieve_locale::{collate, fold_case};
+
+/// Значение поля записи для фильтрации и сортировки в памяти
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldValue {
+    /// Строка
+    String(String),
+
+    /// Целое число
+    Int(i64),
+
+    /// Число с плавающей точкой
+    Float(f64),
+
+    /// Логическое значение
+    Bool(bool),
+
+    /// Отсутствующее значение (`NULL`)
+    Null,
+}
+
+/// Реализация методов для FieldValue
+impl FieldValue {
+    /// Создает строковое значение из любого типа, реализующего `Display`
+    /// 
+    /// Позволяет фильтровать по перечислениям и другим типам по их
+    /// строковому представлению.
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use std::fmt;
+    /// # use sieve::{filter_items, FieldValue, SieveModel};
+    /// #[derive(Debug, PartialEq)]
+    /// enum Genre { Rock, Jazz }
+    /// 
+    /// impl fmt::Display for Genre {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         match self {
+    ///             Genre::Rock => write!(f, "rock"),
+    ///             Genre::Jazz => write!(f, "jazz"),
+    ///         }
+    ///     }
+    /// }
+    /// 
+    /// let sieve = SieveModel::new(&None, &None, &Some("genre==jazz".to_string()), &None);
+    /// let items = filter_items(vec![Genre::Rock, Genre::Jazz], &sieve, |genre, name| {
+    ///     (name == "genre").then(|| FieldValue::from_display(genre))
+    /// });
+    /// assert_eq!(items, vec![Genre::Jazz]);
+    /// ```
+    pub fn from_display<D: fmt::Display + ?Sized>(value: &D) -> Self {
+        FieldValue::String(value.to_string())
+    }
+
+    /// Проверяет, является ли значение отсутствующим (`NULL`)
+    pub fn is_null(&self) -> bool {
+        matches!(self, FieldValue::Null)
+    }
+
+    /// Сравнивает значение поля со значением из фильтра
+    ///
+    /// Если обе стороны разбираются как конечные числа, сравнение числовое,
+    /// иначе - лексикографическое.
+    ///
+    /// # Примеры
+    /// ```
+    /// # use std::cmp::Ordering;
+    /// # use sieve::{filter_items, FieldValue, SieveModel};
+    /// assert_eq!(FieldValue::Int(10).compare("9", false), Ordering::Greater);
+    /// assert_eq!(FieldValue::from("NaN").compare("1", false), Ordering::Greater);
+    ///
+    /// // `NaN` и `Infinity` - строки, а не числа
+    /// let titles = |filters: &str| {
+    ///     let sieve = SieveModel::new(&None, &None, &Some(filters.to_string()), &None);
+    ///     filter_items(vec!["NaN", "Infinity", "Rock", "1", "7"], &sieve, |title, _| Some(FieldValue::from(*title)))
+    /// };
+    /// assert_eq!(titles("title==1"), vec!["1"]);
+    /// // с числом `5` они сравниваются как строки, как и `Rock`
+    /// assert_eq!(titles("title>5"), vec!["NaN", "Infinity", "Rock", "7"]);
+    /// assert_eq!(titles("title<5"), vec!["1"]);
+    /// assert_eq!(FieldValue::from("10").compare("9", false), Ordering::Greater);
+    /// assert_eq!(FieldValue::from("b").compare("a9", false), Ordering::Greater);
+    /// assert_eq!(FieldValue::from("ROCK").compare("rock", true), Ordering::Equal);
+    /// ```
+    pub fn compare(&self, value: &str, case_insensitive: bool) -> Ordering {
+        self.compare_in(value, case_insensitive, None)
+    }
+
+    /// Сравнивает значение поля со значением из фильтра, приводя регистр
+    /// по правилам локали
+    pub(crate) fn compare_in(&self, value: &str, case_insensitive: bool, locale: Option<&str>) -> Ordering {
+        // Отсутствующее значение меньше любого другого
+        if self.is_null() {
+            return Ordering::Less;
+        }
+
+        let left = self.to_string();
+        if let (Some(a), Some(b)) = (parse_number(&left), parse_number(value)) {
+            return a.total_cmp(&b);
+        }
+        if case_insensitive {
+            fold_case(&left, locale).cmp(&fold_case(value, locale))
+        } else {
+            left.as_str().cmp(value)
+        }
+    }
+
+    /// Сравнивает два значения для сортировки с учетом правил локали
+    /// 
+    /// Числа сравниваются численно и идут раньше строк, строки сравниваются
+    /// по правилам локали, поэтому порядок полный даже для смешанных значений.
+    pub(crate) fn collate(&self, other: &FieldValue, locale: Option<&str>) -> Ordering {
+        let (left, right) = (self.to_string(), other.to_string());
+        match (parse_number(&left), parse_number(&right)) {
+            (Some(a), Some(b)) => a.total_cmp(&b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => collate(&left, &right, locale),
+        }
+    }
+}
+
+/// Разбирает строку как конечное число
+/// 
+/// `NaN`, `inf` и `Infinity` числами не считаются и сравниваются как строки.
+fn parse_number(value: &str) -> Option<f64> {
+    value.parse::<f64>().ok().filter(|number| number.is_finite())
+}
+
+impl fmt::Display for FieldValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FieldValue::String(value) => write!(f, "{}", value),
+            FieldValue::Int(value) => write!(f, "{}", value),
+            FieldValue::Float(value) => write!(f, "{}", value),
+            FieldValue::Bool(value) => write!(f, "{}", value),
+            FieldValue::Null => Ok(()),
+        }
+    }
+}
+
+impl From<String> for FieldValue {
+    fn from(value: String) -> Self {
+        FieldValue::String(value)
+    }
+}
+
+impl From<&str> for FieldValue {
+    fn from(value: &str) -> Self {
+        FieldValue::String(value.to_string())
+    }
+}
+
+impl From<i64> for FieldValue {
+    fn from(value: i64) -> Self {
+        FieldValue::Int(value)
+    }
+}
+
+impl From<f64> for FieldValue {
+    fn from(value: f64) -> Self {
+        FieldValue::Float(value)
+    }
+}
+
+impl From<bool> for FieldValue {
+    fn from(value: bool) -> Self {
+        FieldValue::Bool(value)
+    }
+}
+
+impl<V: Into<FieldValue>> From<Option<V>> for FieldValue {
+    fn from(value: Option<V>) -> Self {
+        value.map(Into::into).unwrap_or(FieldValue::Null)
+    }
+}
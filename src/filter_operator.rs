@@ -1,3 +1,4 @@
+use std::fmt;
 use std::str::FromStr;
 
 /// Операторы фильтрации
@@ -31,6 +32,35 @@ pub enum FilterOperator {
     EndsWith,
 }
 
+/// Реализация методов для FilterOperator
+impl FilterOperator {
+    /// Возвращает оценку селективности оператора: чем больше значение,
+    /// тем меньше записей обычно проходит условие
+    /// 
+    /// Используется для переупорядочивания термов, чтобы сначала проверялись
+    /// самые селективные условия. Точное совпадение - самое селективное,
+    /// поиск подстроки - наименее селективный.
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use sieve::FilterOperator;
+    /// assert!(FilterOperator::Equals.selectivity_hint() > FilterOperator::Contains.selectivity_hint());
+    /// ```
+    pub fn selectivity_hint(&self) -> u8 {
+        match self {
+            FilterOperator::Equals => 100,
+            FilterOperator::GreaterThan
+            | FilterOperator::LessThan
+            | FilterOperator::GreaterThanOrEqualTo
+            | FilterOperator::LessThanOrEqualTo => 60,
+            FilterOperator::StartsWith => 40,
+            FilterOperator::EndsWith => 30,
+            FilterOperator::NotEquals => 20,
+            FilterOperator::Contains => 10,
+        }
+    }
+}
+
 /// Реализация FromStr для FilterOperator
 impl FromStr for FilterOperator {
     type Err = String;
@@ -51,3 +81,29 @@ impl FromStr for FilterOperator {
         }
     }
 }
+
+
+/// Реализация Display для FilterOperator
+impl fmt::Display for FilterOperator {
+    /// Выводит оператор в синтаксисе фильтров
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use sieve::FilterOperator;
+    /// assert_eq!(FilterOperator::EndsWith.to_string(), "_-=");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let operator = match self {
+            FilterOperator::Equals => "==",
+            FilterOperator::NotEquals => "!=",
+            FilterOperator::GreaterThan => ">",
+            FilterOperator::LessThan => "<",
+            FilterOperator::GreaterThanOrEqualTo => ">=",
+            FilterOperator::LessThanOrEqualTo => "<=",
+            FilterOperator::Contains => "@=",
+            FilterOperator::StartsWith => "_=",
+            FilterOperator::EndsWith => "_-=",
+        };
+        write!(f, "{}", operator)
+    }
+}
@@ -1,16 +1,66 @@
+use std::cmp::Ordering;
 use std::str::FromStr;
+use super::field_value::FieldValue;
 use super::filter_operator::FilterOperator;
+use super::sieve_escape::{bracket_depth, split_escaped, split_list, split_raw, unescape_value};
+use super::sieve_locale::fold_case;
+use super::sieve_regex::{CHAIN_PATTERN, CHAIN_PREFIX_PATTERN};
+use super::value_type::ValueType;
+
+/// Значение фильтра, обозначающее отсутствие значения (`NULL`)
+/// 
+/// `поле==$null` выбирает записи без значения поля, `поле!=$null` - со значением.
+/// Пустая строка (`поле==""` или `поле==`) - обычное значение, а не `NULL`.
+/// Строку `$null` как обычное значение записывают экранированной (`поле==\$null`)
+/// или в кавычках (`поле=="$null"`).
+/// 
+/// Генерация SQL и фильтрация в памяти трактуют эти случаи одинаково:
+/// 
+/// ```
+/// # use sieve::{filter_items, FieldValue, SieveModel, SqlDialect};
+/// let statuses = vec![None, Some(""), Some("active")];
+/// let check = |filters: &str, where_clause: &str, params: Vec<&str>, expected: Vec<Option<&str>>| {
+///     let model = SieveModel::new(&None, &None, &Some(filters.to_string()), &None);
+///     let parts = model.describe_sql(SqlDialect::Postgres);
+///     assert_eq!(parts.where_clause(), Some(where_clause.to_string()));
+///     assert_eq!(parts.params(), params);
+///     let items = filter_items(statuses.clone(), &model, |status, _| Some(FieldValue::from(*status)));
+///     assert_eq!(items, expected);
+/// };
+/// 
+/// check("status==$null", r#""status" IS NULL"#, vec![], vec![None]);
+/// check("status!=$null", r#""status" IS NOT NULL"#, vec![], vec![Some(""), Some("active")]);
+/// check(r#"status=="""#, r#""status" = $1"#, vec![""], vec![Some("")]);
+/// check("status==", r#""status" = $1"#, vec![""], vec![Some("")]);
+/// // как и в SQL, сравнение NULL с обычным значением не выполняется
+/// check(r#"status!="""#, r#""status" <> $1"#, vec![""], vec![Some("active")]);
+/// 
+/// // экранированный `$null` - обычная строка
+/// let statuses = vec![None, Some("$null")];
+/// for filters in ["status==\\$null", r#"status=="$null""#] {
+///     let model = SieveModel::new(&None, &None, &Some(filters.to_string()), &None);
+///     assert_eq!(model.describe_sql(SqlDialect::Postgres).params(), vec!["$null"]);
+///     let items = filter_items(statuses.clone(), &model, |status, _| Some(FieldValue::from(*status)));
+///     assert_eq!(items, vec![Some("$null")]);
+/// }
+/// # use std::str::FromStr;
+/// # use sieve::FilterTerm;
+/// assert!(FilterTerm::from_str("status==$null|\\$null").is_err());
+/// ```
+pub const NULL_VALUE: &str = "$null";
 
 /// Термин фильтрации, представляющий одно условие фильтрации
 /// 
 /// # Примеры
 /// ```
-/// let filter = FilterTerm::from_str("title@=Rock");
+/// # use std::str::FromStr;
+/// # use sieve::{FilterTerm, FilterOperator};
+/// let filter = FilterTerm::from_str("title@=Rock").unwrap();
 /// assert_eq!(filter.names(), vec!["title"]);
 /// assert_eq!(filter.operator(), FilterOperator::Contains);
 /// assert_eq!(filter.values(), vec!["Rock"]);
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct FilterTerm {
     /// Имена полей для фильтрации (может быть несколько при использовании |)
     names: Vec<String>,
@@ -23,10 +73,71 @@ pub struct FilterTerm {
 
     /// Флаг регистронезависимого поиска
     case_insensitive: bool,
+
+    /// Флаг инвертирования условия (`!@=`, `!_=`, `!_-=`)
+    negated: bool,
+
+    /// Явный тип значений (`year==2000:int`)
+    value_type: Option<ValueType>,
+
+    /// Флаг того, что значение `$null` записано экранированным (`\$null`)
+    /// и означает строку, а не `NULL`
+    literal_null: bool,
 }
 
 /// Реализация методов для FilterTerm
 impl FilterTerm {
+    /// Создает терм фильтрации без разбора строки
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use sieve::{FilterTerm, FilterOperator};
+    /// let filter = FilterTerm::new(vec!["artist".to_string()], FilterOperator::Equals, vec!["Queen".to_string()], false);
+    /// assert_eq!(filter.names(), vec!["artist"]);
+    /// ```
+    pub fn new(names: Vec<String>, operator: FilterOperator, values: Vec<String>, case_insensitive: bool) -> Self {
+        Self { names, values, operator, case_insensitive, negated: false, value_type: None, literal_null: false }
+    }
+
+    /// Возвращает копию терма с другими именами полей
+    pub(crate) fn with_names(&self, names: Vec<String>) -> Self {
+        Self { names, ..self.clone() }
+    }
+
+    /// Задает флаг инвертирования условия
+    pub fn with_negated(mut self, negated: bool) -> Self {
+        self.negated = negated;
+        self
+    }
+
+    /// Задает явный тип значений фильтра
+    pub fn with_value_type(mut self, value_type: Option<ValueType>) -> Self {
+        self.value_type = value_type;
+        self
+    }
+
+    /// Заменяет оператор фильтрации, например при оптимизации запроса
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use sieve::{FilterOperator, FilterTerm, SieveModel, SqlDialect};
+    /// let mut filter = FilterTerm::new(vec!["title".to_string()], FilterOperator::Contains, vec!["Rock".to_string()], false);
+    /// let sql = |filter: &FilterTerm| {
+    ///     let model = SieveModel::from_terms(&None, &None, vec![filter.clone()], vec![]);
+    ///     let parts = model.describe_sql(SqlDialect::Postgres);
+    ///     (parts.where_clause().unwrap(), parts.params())
+    /// };
+    /// assert_eq!(sql(&filter), (r#""title" LIKE $1 ESCAPE '\'"#.to_string(), vec!["%Rock%".to_string()]));
+    /// 
+    /// // поле без полнотекстового индекса: поиск подстроки заменяется точным совпадением
+    /// filter.set_operator(FilterOperator::Equals);
+    /// assert_eq!(filter.operator(), FilterOperator::Equals);
+    /// assert_eq!(sql(&filter), (r#""title" = $1"#.to_string(), vec!["Rock".to_string()]));
+    /// ```
+    pub fn set_operator(&mut self, operator: FilterOperator) {
+        self.operator = operator;
+    }
+
     /// Возвращает имена полей для фильтрации
     /// 
     /// # Примеры
@@ -67,6 +178,390 @@ impl FilterTerm {
     pub fn case_insensitive(&self) -> bool {
         self.case_insensitive
     }
+
+    /// Возвращает флаг инвертирования условия
+    /// 
+    /// # Примеры
+    /// - `true` - `title!@=Rock` или `title@=!Rock` (не содержит)
+    /// - `false` - `title@=Rock` или `title!=Rock` (оператор "не равно" сам по себе не инвертирован)
+    pub fn negated(&self) -> bool {
+        self.negated
+    }
+
+    /// Возвращает явный тип значений, заданный суффиксом `:int`, `:float`, `:bool` или `:date`
+    pub fn value_type(&self) -> Option<ValueType> {
+        self.value_type
+    }
+
+    /// Возвращает значения фильтра, приведенные к явному типу
+    /// или к типу, определенному по самому значению
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use std::str::FromStr;
+    /// # use sieve::{FieldValue, FilterTerm, ValueType};
+    /// let filter = FilterTerm::from_str("year==2000:int").unwrap();
+    /// assert_eq!(filter.value_type(), Some(ValueType::Int));
+    /// assert_eq!(filter.typed_values(), vec![FieldValue::Int(2000)]);
+    /// 
+    /// let filter = FilterTerm::from_str("rating>=4:float").unwrap();
+    /// assert_eq!(filter.typed_values(), vec![FieldValue::Float(4.0)]);
+    /// 
+    /// let filter = FilterTerm::from_str("active==1:bool").unwrap();
+    /// assert_eq!(filter.values(), vec!["1"]);
+    /// assert_eq!(filter.typed_values(), vec![FieldValue::Bool(true)]);
+    /// 
+    /// let filter = FilterTerm::from_str("created_time>=2023-01-01:date").unwrap();
+    /// assert_eq!(filter.value_type(), Some(ValueType::Date));
+    /// assert_eq!(filter.typed_values(), vec![FieldValue::from("2023-01-01")]);
+    /// 
+    /// // тип применяется ко всем значениям терма
+    /// let filter = FilterTerm::from_str("code==[007,42]:int").unwrap();
+    /// assert_eq!(filter.typed_values(), vec![FieldValue::Int(7), FieldValue::Int(42)]);
+    /// 
+    /// // без аннотации тип определяется по значению
+    /// let filter = FilterTerm::from_str("code==007|true|Rock").unwrap();
+    /// assert_eq!(filter.value_type(), None);
+    /// assert_eq!(filter.typed_values(), vec![FieldValue::Int(7), FieldValue::Bool(true), FieldValue::from("Rock")]);
+    /// 
+    /// // значение, не приводимое к типу, - ошибка разбора
+    /// assert!(FilterTerm::from_str("year==soon:int").is_err());
+    /// assert!(FilterTerm::from_str("created_time>=yesterday:date").is_err());
+    /// 
+    /// // неизвестный или экранированный суффикс остается частью значения
+    /// assert_eq!(FilterTerm::from_str("time==10:30").unwrap().values(), vec!["10:30"]);
+    /// assert_eq!(FilterTerm::from_str("tag==a\\:int").unwrap().values(), vec!["a:int"]);
+    /// 
+    /// // явный тип учитывается при сравнении в памяти и в параметрах SQL
+    /// # use sieve::{SieveModel, SqlDialect};
+    /// let filter = FilterTerm::from_str("active==1:bool").unwrap();
+    /// assert!(filter.matches_field(&FieldValue::Bool(true)));
+    /// let model = SieveModel::new(&None, &None, &Some("active==1:bool".to_string()), &None);
+    /// assert_eq!(model.describe_sql(SqlDialect::Postgres).params(), vec!["true"]);
+    /// ```
+    pub fn typed_values(&self) -> Vec<FieldValue> {
+        self.values
+            .iter()
+            .map(|value| match self.value_type {
+                _ if self.is_null_value(value) => FieldValue::Null,
+                Some(value_type) => value_type.coerce(value).unwrap_or_else(|| FieldValue::from(value.as_str())),
+                None => ValueType::infer(value),
+            })
+            .collect()
+    }
+
+    /// Возвращает значение фильтра в каноническом виде его явного типа
+    /// (`1:bool` - `true`), чтобы сравнение и параметры SQL не зависели от записи
+    pub(crate) fn normalized_value(&self, value: &str) -> String {
+        self.value_type
+            .and_then(|value_type| value_type.coerce(value))
+            .map(|value| value.to_string())
+            .unwrap_or_else(|| value.to_string())
+    }
+
+    /// Возвращает условие без значений для безопасного логирования
+    /// 
+    /// Поля, оператор и его модификаторы (`!`, `*` и суффикс `:тип`)
+    /// сохраняются, значения заменяются на `***`, поэтому в журнал
+    /// не попадают персональные данные.
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use std::str::FromStr;
+    /// # use sieve::FilterTerm;
+    /// let filter = FilterTerm::from_str("email==john@example.com").unwrap();
+    /// assert_eq!(filter.to_predicate_string(), "email == ***");
+    /// 
+    /// let filter = FilterTerm::from_str("(first_name|last_name)_=John|Jane").unwrap();
+    /// assert_eq!(filter.to_predicate_string(), "(first_name|last_name) _= ***");
+    /// assert!(!filter.to_predicate_string().contains("John"));
+    /// 
+    /// // модификаторы различают форму условия
+    /// assert_eq!(FilterTerm::from_str("email==*A").unwrap().to_predicate_string(), "email ==* ***");
+    /// assert_eq!(FilterTerm::from_str("year>=2000:int").unwrap().to_predicate_string(), "year >= ***:int");
+    /// assert_eq!(FilterTerm::from_str("title!@=*Rock").unwrap().to_predicate_string(), "title !@=* ***");
+    /// ```
+    pub fn to_predicate_string(&self) -> String {
+        let names = if self.names.len() == 1 {
+            self.names.join("|")
+        } else {
+            format!("({})", self.names.join("|"))
+        };
+        let negation = if self.negated { "!" } else { "" };
+        let modifier = if self.case_insensitive { "*" } else { "" };
+        let value_type = self.value_type.map(|value_type| format!(":{}", value_type)).unwrap_or_default();
+        format!("{} {}{}{} ***{}", names, negation, self.operator, modifier, value_type)
+    }
+
+    /// Проверяет, является ли строка цепочкой сравнений (начинается с числа и оператора)
+    pub fn is_chain(filter: &str) -> bool {
+        CHAIN_PREFIX_PATTERN.is_match(filter)
+    }
+
+    /// Разбирает цепочку сравнений `18<=age<=65` в два терма, объединяемых через И
+    /// 
+    /// Оба оператора должны быть направлены в одну сторону (`<`/`<=` или `>`/`>=`).
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use sieve::{FilterTerm, FilterOperator};
+    /// let terms = FilterTerm::from_chain("18<=age<65").unwrap();
+    /// assert_eq!(terms[0].names(), vec!["age"]);
+    /// assert_eq!(terms[0].operator(), FilterOperator::GreaterThanOrEqualTo);
+    /// assert_eq!(terms[0].values(), vec!["18"]);
+    /// assert_eq!(terms[1].operator(), FilterOperator::LessThan);
+    /// assert_eq!(terms[1].values(), vec!["65"]);
+    /// 
+    /// assert!(FilterTerm::from_chain("18<=age>=65").is_err());
+    /// assert!(FilterTerm::from_chain("18<=age<=").is_err());
+    /// assert!(FilterTerm::from_chain("18<=<=65").is_err());
+    /// ```
+    pub fn from_chain(filter: &str) -> Result<Vec<FilterTerm>, String> {
+        let captures = CHAIN_PATTERN
+            .captures(filter)
+            .ok_or_else(|| format!("Некорректная цепочка сравнений: {}", filter))?;
+        let (low, left, name, right, high) = (&captures[1], &captures[2], &captures[3], &captures[4], &captures[5]);
+
+        if left.starts_with('<') != right.starts_with('<') {
+            return Err(format!("Операторы цепочки направлены в разные стороны: {}", filter));
+        }
+
+        // `18<=age` эквивалентно `age>=18`
+        let flipped = match left {
+            "<" => ">",
+            "<=" => ">=",
+            ">" => "<",
+            _ => "<=",
+        };
+        Ok(vec![
+            FilterTerm::from_str(&format!("{}{}{}", name, flipped, low))?,
+            FilterTerm::from_str(&format!("{}{}{}", name, right, high))?,
+        ])
+    }
+
+    /// Проверяет, удовлетворяет ли запись условию фильтрации
+    /// 
+    /// `field` возвращает значение поля записи по имени или `None`,
+    /// если такого поля нет. Неизвестные поля пропускаются, а терм,
+    /// в котором нет ни одного известного поля, считается выполненным.
+    /// 
+    /// Несколько полей и несколько значений объединяются через ИЛИ:
+    /// `(title|artist)@=Rock|Pop` выполняется, если хотя бы одно поле содержит
+    /// хотя бы одно значение. Для `!=` используется И: `(title|artist)!=Queen`
+    /// выполняется, только если все поля отличаются от всех значений.
+    /// Инвертированный терм (`!@=`) выполняется, если исходное условие
+    /// не выполняется ни для одного поля; поле со значением `NULL`, как и в SQL,
+    /// не проходит инвертированное условие.
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use std::str::FromStr;
+    /// # use sieve::{FieldValue, FilterTerm};
+    /// let field = |name: &str| match name {
+    ///     "title" => Some(FieldValue::from("Queen")),
+    ///     "artist" => Some(FieldValue::from("Freddie")),
+    ///     _ => None,
+    /// };
+    /// 
+    /// // хотя бы одно поле содержит значение
+    /// assert!(FilterTerm::from_str("(title|artist)@=Queen").unwrap().matches(field));
+    /// // все поля должны отличаться от значения
+    /// assert!(!FilterTerm::from_str("(title|artist)!=Queen").unwrap().matches(field));
+    /// assert!(FilterTerm::from_str("(title|artist)!=Brian").unwrap().matches(field));
+    /// // инвертированный терм не должен выполняться ни для одного поля
+    /// assert!(!FilterTerm::from_str("(title|artist)!@=Queen").unwrap().matches(field));
+    /// assert!(FilterTerm::from_str("(title|artist)@=!Brian").unwrap().matches(field));
+    /// 
+    /// // несколько полей и несколько значений: достаточно любого сочетания поля и значения
+    /// let filter = FilterTerm::from_str("(title|artist)@=*rock|jazz").unwrap();
+    /// for (title, artist, expected) in [
+    ///     ("Rock Me", "Falco", true),
+    ///     ("Take Five", "Dave Brubeck JAZZ Quartet", true),
+    ///     ("Acid Jazz Mix", "Rockers", true),
+    ///     ("Yesterday", "The Beatles", false),
+    /// ] {
+    ///     let field = |name: &str| match name {
+    ///         "title" => Some(FieldValue::from(title)),
+    ///         "artist" => Some(FieldValue::from(artist)),
+    ///         _ => None,
+    ///     };
+    ///     assert_eq!(filter.matches(field), expected, "{} - {}", title, artist);
+    /// }
+    /// ```
+    pub fn matches<F>(&self, field: F) -> bool
+    where
+        F: Fn(&str) -> Option<FieldValue>,
+    {
+        self.matches_fields(|name| field(name).map(|value| self.matches_field(&value)))
+    }
+
+    /// Проверяет значение одного поля на соответствие значениям фильтра
+    /// 
+    /// Значения объединяются через ИЛИ, для `!=` - через И.
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use std::str::FromStr;
+    /// # use sieve::{FieldValue, FilterTerm};
+    /// let filter = FilterTerm::from_str("year>=1970|1990").unwrap();
+    /// assert!(filter.matches_field(&FieldValue::from(1980)));
+    /// assert!(!filter.matches_field(&FieldValue::from(1960)));
+    /// ```
+    pub fn matches_field(&self, field: &FieldValue) -> bool {
+        self.matches_field_in(field, None)
+    }
+
+    /// Проверяет значение одного поля, приводя регистр по правилам локали
+    pub(crate) fn matches_field_in(&self, field: &FieldValue, locale: Option<&str>) -> bool {
+        if self.negated {
+            return !field.is_null() && !self.values.iter().any(|value| self.matches_value(field, value, locale));
+        }
+
+        match self.operator {
+            FilterOperator::NotEquals => self.values.iter().all(|value| self.matches_value(field, value, locale)),
+            _ => self.values.iter().any(|value| self.matches_value(field, value, locale)),
+        }
+    }
+
+    /// Объединяет результаты проверки отдельных полей терма
+    /// 
+    /// `field` возвращает результат проверки поля по имени или `None`,
+    /// если поле неизвестно. Поля объединяются через ИЛИ, для `!=` и
+    /// инвертированных термов - через И.
+    pub(crate) fn matches_fields<F>(&self, field: F) -> bool
+    where
+        F: Fn(&str) -> Option<bool>,
+    {
+        let results: Vec<bool> = self.names.iter().filter_map(|name| field(name)).collect();
+        if results.is_empty() {
+            return true;
+        }
+
+        match self.operator {
+            _ if self.negated => results.into_iter().all(|result| result),
+            FilterOperator::NotEquals => results.into_iter().all(|result| result),
+            _ => results.into_iter().any(|result| result),
+        }
+    }
+
+    /// Возвращает имя поля и значение, если терм - простое равенство `поле==значение`
+    /// с одним полем и одним значением, без инвертирования, явного типа и `$null`
+    pub(crate) fn single_equality(&self) -> Option<(&str, &str)> {
+        match (self.names.as_slice(), self.values.as_slice()) {
+            ([name], [value])
+                if self.operator == FilterOperator::Equals
+                    && !self.negated
+                    && self.value_type.is_none()
+                    && !self.is_null_value(value) =>
+            {
+                Some((name, value))
+            }
+            _ => None,
+        }
+    }
+
+    /// Проверяет, что значение фильтра обозначает `NULL`, а не строку `$null`
+    pub(crate) fn is_null_value(&self, value: &str) -> bool {
+        value == NULL_VALUE && !self.literal_null
+    }
+
+    /// Проверяет одно значение поля на соответствие одному значению фильтра
+    fn matches_value(&self, field: &FieldValue, value: &str, locale: Option<&str>) -> bool {
+        // Сравнение с NULL, как и в SQL, выполняется только для `==$null` и `!=$null`
+        match (&self.operator, self.is_null_value(value)) {
+            (FilterOperator::Equals, true) => return field.is_null(),
+            (FilterOperator::NotEquals, true) => return !field.is_null(),
+            _ if field.is_null() => return false,
+            _ => {}
+        }
+        let value = &self.normalized_value(value);
+
+        let text = || {
+            if self.case_insensitive {
+                (fold_case(&field.to_string(), locale), fold_case(value, locale))
+            } else {
+                (field.to_string(), value.to_string())
+            }
+        };
+
+        match self.operator {
+            FilterOperator::Equals => field.compare_in(value, self.case_insensitive, locale) == Ordering::Equal,
+            FilterOperator::NotEquals => field.compare_in(value, self.case_insensitive, locale) != Ordering::Equal,
+            FilterOperator::GreaterThan => field.compare_in(value, self.case_insensitive, locale) == Ordering::Greater,
+            FilterOperator::LessThan => field.compare_in(value, self.case_insensitive, locale) == Ordering::Less,
+            FilterOperator::GreaterThanOrEqualTo => field.compare_in(value, self.case_insensitive, locale) != Ordering::Less,
+            FilterOperator::LessThanOrEqualTo => field.compare_in(value, self.case_insensitive, locale) != Ordering::Greater,
+            FilterOperator::Contains => {
+                let (field, value) = text();
+                field.contains(&value)
+            }
+            FilterOperator::StartsWith => {
+                let (field, value) = text();
+                field.starts_with(&value)
+            }
+            FilterOperator::EndsWith => {
+                let (field, value) = text();
+                field.ends_with(&value)
+            }
+        }
+    }
+}
+
+/// Операторы фильтрации в порядке проверки (длинные раньше коротких)
+const OPERATORS: [&str; 12] = ["!_-=", "!@=", "!_=", "_-=", "==", "!=", ">=", "<=", "@=", "_=", ">", "<"];
+
+/// Вспомогательные функции разбора FilterTerm
+impl FilterTerm {
+    /// Обрезает пробелы вокруг значения, снимает двойные или одинарные кавычки
+    /// (`"  Rock "` - значение `  Rock `, `'M'` - значение `M`) и удаляет экранирование
+    /// 
+    /// Пробелы обрезаются до удаления экранирования, поэтому
+    /// экранированный пробел (`\ Rock`) сохраняется.
+    fn parse_value(value: &str) -> String {
+        let value = value.trim();
+        let value = ['"', '\'']
+            .iter()
+            .find_map(|quote| value.strip_prefix(*quote).and_then(|value| value.strip_suffix(*quote)))
+            .unwrap_or(value);
+        unescape_value(value)
+    }
+
+    /// Отделяет суффикс явного типа `:int`, `:float`, `:bool` или `:date` от значений
+    /// 
+    /// Неизвестный суффикс и экранированное двоеточие (`\:int`) остаются частью значения.
+    fn split_value_type(value_str: &str) -> (&str, Option<ValueType>) {
+        let Some((head, suffix)) = value_str.rsplit_once(':') else {
+            return (value_str, None);
+        };
+        let escaped = head.chars().rev().take_while(|c| *c == '\\').count() % 2 == 1;
+        match ValueType::from_str(suffix.trim()) {
+            Ok(value_type) if !escaped => (head, Some(value_type)),
+            _ => (value_str, None),
+        }
+    }
+
+    /// Отделяет имя поля от оператора и значения
+    /// 
+    /// Имя заканчивается там, где начинается оператор, поэтому символы
+    /// операторов допустимы внутри имени (`created_time`, `first-name`).
+    /// Обратный слеш экранирует следующий символ: `\@field==x` - поле `@field`.
+    fn split_name(filter: &str) -> (String, &str) {
+        let mut name = String::new();
+        let mut chars = filter.char_indices();
+        while let Some((index, c)) = chars.next() {
+            if c == '\\' {
+                if let Some((_, escaped)) = chars.next() {
+                    name.push(escaped);
+                }
+                continue;
+            }
+            if OPERATORS.iter().any(|operator| filter[index..].starts_with(operator)) {
+                return (name.trim().to_string(), &filter[index..]);
+            }
+            name.push(c);
+        }
+        (name.trim().to_string(), "")
+    }
 }
 
 impl FromStr for FilterTerm {
@@ -76,15 +571,139 @@ impl FromStr for FilterTerm {
     /// 
     /// # Примеры
     /// ```
-    /// let filter = FilterTerm::from_str("title@=Rock");
+    /// # use std::str::FromStr;
+    /// # use sieve::{FilterTerm, FilterOperator};
+    /// let filter = FilterTerm::from_str("title@=Rock").unwrap();
     /// assert_eq!(filter.names(), vec!["title"]);
     /// assert_eq!(filter.operator(), FilterOperator::Contains);
     /// assert_eq!(filter.values(), vec!["Rock"]);
     /// 
-    /// let filter = FilterTerm::from_str("title==Rock|Pop");
+    /// let filter = FilterTerm::from_str("title==Rock|Pop").unwrap();
     /// assert_eq!(filter.names(), vec!["title"]);
     /// assert_eq!(filter.operator(), FilterOperator::Equals);
     /// assert_eq!(filter.values(), vec!["Rock", "Pop"]);
+    /// 
+    /// let filter = FilterTerm::from_str("status==[active, pending]").unwrap();
+    /// assert_eq!(filter.values(), vec!["active", "pending"]);
+    /// 
+    /// // символы операторов внутри имени и экранирование в имени
+    /// let filter = FilterTerm::from_str("created_time>=2023-01-01").unwrap();
+    /// assert_eq!(filter.names(), vec!["created_time"]);
+    /// assert_eq!(filter.operator(), FilterOperator::GreaterThanOrEqualTo);
+    /// 
+    /// let filter = FilterTerm::from_str("\\@field==x").unwrap();
+    /// assert_eq!(filter.names(), vec!["@field"]);
+    /// assert_eq!(filter.operator(), FilterOperator::Equals);
+    /// assert_eq!(filter.values(), vec!["x"]);
+    /// 
+    /// let filter = FilterTerm::from_str("a\\=b==c").unwrap();
+    /// assert_eq!(filter.names(), vec!["a=b"]);
+    /// assert_eq!(filter.values(), vec!["c"]);
+    /// 
+    /// // инвертирование перед оператором
+    /// for (input, operator) in [
+    ///     ("title!@=Rock", FilterOperator::Contains),
+    ///     ("title!_=Rock", FilterOperator::StartsWith),
+    ///     ("title!_-=Rock", FilterOperator::EndsWith),
+    /// ] {
+    ///     let filter = FilterTerm::from_str(input).unwrap();
+    ///     assert_eq!(filter.names(), vec!["title"]);
+    ///     assert_eq!(filter.operator(), operator);
+    ///     assert_eq!(filter.values(), vec!["Rock"]);
+    ///     assert!(filter.negated());
+    /// }
+    /// 
+    /// // `!=` - оператор "не равно", а не инвертированное равенство
+    /// let filter = FilterTerm::from_str("title!=Rock").unwrap();
+    /// assert_eq!(filter.operator(), FilterOperator::NotEquals);
+    /// assert!(!filter.negated());
+    /// 
+    /// // инвертирование в значении и его экранирование
+    /// let filter = FilterTerm::from_str("title@=!Rock").unwrap();
+    /// assert_eq!(filter.values(), vec!["Rock"]);
+    /// assert!(filter.negated());
+    /// 
+    /// let filter = FilterTerm::from_str("title@=\\!Rock").unwrap();
+    /// assert_eq!(filter.values(), vec!["!Rock"]);
+    /// assert!(!filter.negated());
+    /// 
+    /// // экранированные разделители значений
+    /// let filter = FilterTerm::from_str("artist==AC\\|DC|Queen").unwrap();
+    /// assert_eq!(filter.values(), vec!["AC|DC", "Queen"]);
+    /// 
+    /// let filter = FilterTerm::from_str("tag==[a\\,b,c]").unwrap();
+    /// assert_eq!(filter.values(), vec!["a,b", "c"]);
+    /// 
+    /// let filter = FilterTerm::from_str("path==C:\\\\").unwrap();
+    /// assert_eq!(filter.values(), vec!["C:\\"]);
+    /// 
+    /// // двойное отрицание дает обычное условие
+    /// let filter = FilterTerm::from_str("title!@=!Rock").unwrap();
+    /// assert_eq!(filter.operator(), FilterOperator::Contains);
+    /// assert_eq!(filter.values(), vec!["Rock"]);
+    /// assert!(!filter.negated());
+    /// 
+    /// let filter = FilterTerm::from_str("title!_=!The|!A").unwrap();
+    /// assert_eq!(filter.values(), vec!["The", "!A"]);
+    /// assert!(!filter.negated());
+    /// 
+    /// let filter = FilterTerm::from_str("title!@=\\!Rock").unwrap();
+    /// assert_eq!(filter.values(), vec!["!Rock"]);
+    /// assert!(filter.negated());
+    /// 
+    /// let filter = FilterTerm::from_str("title==!Rock").unwrap();
+    /// assert_eq!(filter.values(), vec!["!Rock"]);
+    /// assert!(!filter.negated());
+    /// 
+    /// // `*` после оператора включает регистронезависимое сравнение
+    /// for (operator, expected) in [
+    ///     ("==", FilterOperator::Equals),
+    ///     ("!=", FilterOperator::NotEquals),
+    ///     (">", FilterOperator::GreaterThan),
+    ///     ("<", FilterOperator::LessThan),
+    ///     (">=", FilterOperator::GreaterThanOrEqualTo),
+    ///     ("<=", FilterOperator::LessThanOrEqualTo),
+    ///     ("@=", FilterOperator::Contains),
+    ///     ("_=", FilterOperator::StartsWith),
+    ///     ("_-=", FilterOperator::EndsWith),
+    /// ] {
+    ///     let filter = FilterTerm::from_str(&format!("title{}Rock", operator)).unwrap();
+    ///     assert_eq!(filter.operator(), expected);
+    ///     assert_eq!(filter.values(), vec!["Rock"]);
+    ///     assert!(!filter.case_insensitive());
+    /// 
+    ///     let filter = FilterTerm::from_str(&format!("title{}*Rock", operator)).unwrap();
+    ///     assert_eq!(filter.operator(), expected);
+    ///     assert_eq!(filter.values(), vec!["Rock"]);
+    ///     assert!(filter.case_insensitive());
+    /// }
+    /// 
+    /// let filter = FilterTerm::from_str("title!@=*Rock").unwrap();
+    /// assert!(filter.negated() && filter.case_insensitive());
+    /// assert_eq!(filter.values(), vec!["Rock"]);
+    /// 
+    /// // модификаторы значения `*` и `!` допускаются в любом порядке
+    /// for filter in ["title@=*!Rock", "title@=!*Rock"] {
+    ///     let filter = FilterTerm::from_str(filter).unwrap();
+    ///     assert!(filter.negated() && filter.case_insensitive());
+    ///     assert_eq!(filter.values(), vec!["Rock"]);
+    /// }
+    /// 
+    /// // каждый модификатор учитывается один раз, повтор - часть значения
+    /// let filter = FilterTerm::from_str("title@=**Rock").unwrap();
+    /// assert_eq!(filter.values(), vec!["*Rock"]);
+    /// let filter = FilterTerm::from_str("title==*!Rock").unwrap();
+    /// assert!(!filter.negated() && filter.case_insensitive());
+    /// assert_eq!(filter.values(), vec!["!Rock"]);
+    /// 
+    /// let filter = FilterTerm::from_str("title==\\*Rock").unwrap();
+    /// assert_eq!(filter.values(), vec!["*Rock"]);
+    /// assert!(!filter.case_insensitive());
+    /// 
+    /// // поля без оператора и значения
+    /// let filter = FilterTerm::from_str("(title|artist)").unwrap();
+    /// assert_eq!(filter.values(), vec![""]);
+    /// ```
     fn from_str(filter: &str) -> Result<Self, Self::Err> {
         let filter = filter.trim();
         if filter.is_empty() {
@@ -95,51 +714,83 @@ impl FromStr for FilterTerm {
         let (names, rest) = if filter.starts_with('(') && filter.contains(')') {
             let end = filter.find(')').unwrap();
             let fields = &filter[1..end];
-            let names = fields.split('|').map(|s| s.trim().to_string()).collect();
+            let names = split_escaped(fields, '|').iter().map(|s| s.trim().to_string()).collect();
             (names, &filter[end + 1..])
         } else {
-            let mut names = Vec::new();
-            let end = filter.find(|c| "=!<>@_-".contains(c)).unwrap_or(filter.len());
-            names.push(filter[..end].trim().to_string());
-            (names, &filter[end..])
+            let (name, rest) = FilterTerm::split_name(filter);
+            (vec![name], rest)
         };
 
-        // Находим оператор
-        let operator = if rest.starts_with("==") {
-            "=="
-        } else if rest.starts_with("!=") {
-            "!="
-        } else if rest.starts_with(">=") {
-            ">="
-        } else if rest.starts_with("<=") {
-            "<="
-        } else if rest.starts_with('>') {
-            ">"
-        } else if rest.starts_with('<') {
-            "<"
-        } else if rest.starts_with("@=") {
-            "@="
-        } else if rest.starts_with("_=") {
-            "_="
-        } else if rest.starts_with("_-=") {
-            "_-="
-        } else {
-            "=="
+        // Находим оператор и получаем значение после него
+        let (operator, value_str) = match OPERATORS.iter().find(|operator| rest.starts_with(*operator)) {
+            Some(operator) => (*operator, &rest[operator.len()..]),
+            None => ("==", rest),
+        };
+
+        // `!` перед оператором инвертирует условие, но `!=` - самостоятельный оператор
+        let (operator, mut negated) = match operator.strip_prefix('!') {
+            Some(positive) if operator != "!=" => (positive, true),
+            _ => (operator, false),
+        };
+
+        // Модификаторы в начале значения допускаются в любом порядке (`@=*!Rock`, `@=!*Rock`):
+        // `*` включает регистронезависимое сравнение (`title==*rock`), а `!` для `@=`, `_=`
+        // и `_-=` тоже инвертирует условие (`title@=!Rock`). `\*` и `\!` означают сами символы.
+        // Двойное отрицание (`title!@=!Rock`) взаимно уничтожается: терм равнозначен `title@=Rock`
+        let mut value_str = value_str.trim();
+        let mut case_insensitive = false;
+        let mut value_negated = false;
+        loop {
+            if let Some(rest) = value_str.strip_prefix('*').filter(|_| !case_insensitive) {
+                case_insensitive = true;
+                value_str = rest;
+            } else if let Some(rest) = value_str.strip_prefix('!').filter(|_| !value_negated && matches!(operator, "@=" | "_=" | "_-=")) {
+                value_negated = true;
+                negated = !negated;
+                value_str = rest;
+            } else {
+                break;
+            }
+        }
+        let value_str = value_str.trim();
+
+        // Суффикс `:тип` в конце задает явный тип всех значений (`year==2000:int`)
+        let (value_str, value_type) = FilterTerm::split_value_type(value_str);
+
+        // Разбираем множественные значения (значение1|значение2 или [значение1,значение2]),
+        // экранированные разделители остаются частью значения
+        // Внутри списка скобки должны быть парными, вне списка `[` - обычный
+        // символ, а `]` без пары - ошибка
+        let values = match value_str.strip_prefix('[') {
+            Some(list) => match list.strip_suffix(']').filter(|list| bracket_depth(list) == Some(0)) {
+                Some(list) => split_list(list),
+                None => return Err(format!("Unbalanced bracket in value {}", value_str)),
+            },
+            None if bracket_depth(value_str).is_none() => return Err(format!("Unbalanced bracket in value {}", value_str)),
+            None => split_raw(value_str, '|', false),
         };
+        // `NULL` обозначает только `$null` без экранирования и кавычек
+        let nulls = values.iter().filter(|value| value.trim() == NULL_VALUE).count();
+        let values: Vec<String> = values.into_iter().map(FilterTerm::parse_value).collect();
+        let literal_null = nulls < values.iter().filter(|value| *value == NULL_VALUE).count();
+        if literal_null && nulls > 0 {
+            return Err(format!("Filter mixes {} and escaped {}", NULL_VALUE, NULL_VALUE));
+        }
 
-        // Получаем значение после оператора
-        let value_str = &rest[operator.len()..];
-        
-        // Разбираем множественные значения (значение1|значение2)
-        let values = value_str.split('|')
-            .map(|s| s.trim().to_string())
-            .collect();
+        if let Some(value_type) = value_type {
+            if let Some(value) = values.iter().find(|value| (*value != NULL_VALUE || literal_null) && value_type.coerce(value).is_none()) {
+                return Err(format!("Value {} is not {}", value, value_type));
+            }
+        }
 
         Ok(FilterTerm {
             names,
             values,
             operator: FilterOperator::from_str(operator).unwrap_or(FilterOperator::Equals),
-            case_insensitive: operator.ends_with('*') || operator == "@="
+            case_insensitive,
+            negated,
+            value_type,
+            literal_null,
         })
     }
 }
\ No newline at end of file
@@ -5,11 +5,52 @@ pub mod filter_operator;
 pub mod sort_order;
 pub mod sort_term;
 pub mod paged_list;
+pub mod field_value;
+pub mod paging;
+pub mod sieve_paginate;
+pub mod compiled_filter;
+pub mod sieve_config;
+pub mod sieve_filter;
+pub mod sieve_order;
+pub mod sieve_apply;
+pub mod sql_dialect;
+pub mod sql_parts;
+pub mod field_type;
+pub mod sieve_schema;
+pub mod schema_error;
+pub mod sieve_escape;
+pub mod sieveable;
+pub mod sieve_parse_error;
+pub mod value_type;
+pub mod normalize_options;
+pub mod diagnostic;
+mod sieve_sql;
+mod sieve_locale;
+mod sieve_normalize;
 
 pub use sieve_regex::COMMA_PATTERN;
-pub use filter_term::FilterTerm;
+pub use filter_term::{FilterTerm, NULL_VALUE};
 pub use filter_operator::FilterOperator;
 pub use sieve_model::SieveModel;
 pub use sort_order::SortOrder;
 pub use sort_term::SortTerm;
-pub use paged_list::PagedList;
\ No newline at end of file
+pub use paged_list::PagedList;
+pub use field_value::FieldValue;
+pub use paging::Paging;
+pub use sieve_paginate::{paginate_parts, paginate_ref, SievePaginate};
+pub use compiled_filter::CompiledFilter;
+pub use sieve_config::{FieldAccessor, FieldEvaluator, SieveConfig};
+pub use sieve_filter::{filter_items, filter_items_diagnostic, filter_items_with, SieveFilter};
+pub use sieve_order::{sort_items, SieveOrder};
+pub use sieve_apply::{apply_sieve_sorted_paged, SieveApplicable};
+pub use sql_dialect::SqlDialect;
+pub use sql_parts::SqlParts;
+pub use field_type::FieldType;
+pub use sieve_schema::SieveSchema;
+pub use schema_error::SchemaError;
+pub use sieve_escape::{escape_value, split_escaped, unescape_value};
+pub use sieveable::Sieveable;
+pub use sieve_parse_error::SieveParseError;
+pub use value_type::ValueType;
+pub use normalize_options::NormalizeOptions;
+pub use diagnostic::Diagnostic;
\ No newline at end of file
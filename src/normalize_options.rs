@@ -0,0 +1,81 @@
+use std::collections::HashMap;
+
+/// Настройки нормализации SieveModel перед применением на сервере
+/// 
+/// # Примеры
+/// ```
+/// # use sieve::NormalizeOptions;
+/// let options = NormalizeOptions::new()
+///     .with_field("name", "title")
+///     .with_max_page_size(100)
+///     .with_tiebreaker("id");
+/// assert_eq!(options.field("name"), "title");
+/// assert_eq!(options.field("year"), "year");
+/// assert_eq!(options.max_page_size(), Some(100));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct NormalizeOptions {
+    /// Переименования полей: публичное имя - имя в схеме
+    field_map: HashMap<String, String>,
+
+    /// Максимальный размер страницы
+    max_page_size: Option<u64>,
+
+    /// Поле, добавляемое в конец сортировки для однозначного порядка
+    tiebreaker: Option<String>,
+
+    /// Флаг удаления термов с неизвестными полями вместо ошибки
+    drop_unknown_fields: bool,
+}
+
+/// Реализация методов для NormalizeOptions
+impl NormalizeOptions {
+    /// Создает настройки без преобразований
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Задает переименование поля из публичного имени в имя схемы
+    pub fn with_field(mut self, alias: &str, field: &str) -> Self {
+        self.field_map.insert(alias.to_string(), field.to_string());
+        self
+    }
+
+    /// Задает максимальный размер страницы
+    pub fn with_max_page_size(mut self, max_page_size: u64) -> Self {
+        self.max_page_size = Some(max_page_size);
+        self
+    }
+
+    /// Задает поле для однозначной сортировки (обычно первичный ключ)
+    pub fn with_tiebreaker(mut self, field: &str) -> Self {
+        self.tiebreaker = Some(field.to_string());
+        self
+    }
+
+    /// Задает удаление термов с неизвестными полями вместо ошибки
+    pub fn with_drop_unknown_fields(mut self, drop_unknown_fields: bool) -> Self {
+        self.drop_unknown_fields = drop_unknown_fields;
+        self
+    }
+
+    /// Возвращает имя поля в схеме для публичного имени
+    pub fn field(&self, name: &str) -> String {
+        self.field_map.get(name).cloned().unwrap_or_else(|| name.to_string())
+    }
+
+    /// Возвращает максимальный размер страницы
+    pub fn max_page_size(&self) -> Option<u64> {
+        self.max_page_size
+    }
+
+    /// Возвращает поле для однозначной сортировки
+    pub fn tiebreaker(&self) -> Option<String> {
+        self.tiebreaker.clone()
+    }
+
+    /// Возвращает флаг удаления термов с неизвестными полями
+    pub fn drop_unknown_fields(&self) -> bool {
+        self.drop_unknown_fields
+    }
+}
@@ -1,15 +1,49 @@
-use serde::Serialize;
+use std::io::Write;
+use serde::ser::{Serialize, SerializeStruct, Serializer};
 
 /// Обобщенная структура для постраничного списка
-#[derive(Debug, Serialize)]
+#[derive(Debug)]
 pub struct PagedList<T> {
     items: Vec<T>,  // Список элементов
     total: u64,     // Общее количество элементов
+    offset: u64,    // Смещение первого элемента страницы (не сериализуется)
+    extra: Option<serde_json::Value>,   // Дополнительные данные ответа (агрегаты и т.п.), поле `meta`
 }
 
 impl<T> PagedList<T> {
     pub fn new(items: Vec<T>, total: u64) -> Self {
-        Self { items, total }
+        Self { items, total, offset: 0, extra: None }
+    }
+
+    /// Задает смещение первого элемента страницы относительно всего списка
+    pub fn with_offset(mut self, offset: u64) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Задает дополнительные данные страницы, которые сериализуются в поле `meta`
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use sieve::PagedList;
+    /// let page = PagedList::new(vec![120, 80], 2).with_extra(serde_json::json!({ "sum": 200, "avg": 100.0 }));
+    /// assert_eq!(
+    ///     serde_json::to_string(&page).unwrap(),
+    ///     r#"{"items":[120,80],"total":2,"meta":{"avg":100.0,"sum":200}}"#
+    /// );
+    /// 
+    /// // без дополнительных данных поле `meta` не выводится
+    /// let page = PagedList::new(vec![1], 1);
+    /// assert_eq!(serde_json::to_string(&page).unwrap(), r#"{"items":[1],"total":1}"#);
+    /// ```
+    pub fn with_extra(mut self, extra: serde_json::Value) -> Self {
+        self.extra = Some(extra);
+        self
+    }
+
+    /// Возвращает дополнительные данные страницы
+    pub fn extra(&self) -> Option<&serde_json::Value> {
+        self.extra.as_ref()
     }
 
     pub fn items(&self) -> &Vec<T> {
@@ -19,4 +53,76 @@ impl<T> PagedList<T> {
     pub fn total(&self) -> u64 {
         self.total
     }
-}
\ No newline at end of file
+
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Возвращает `(from, to, total)` для подписи "показаны X–Y из Z"
+    /// 
+    /// `from` и `to` - номера первого и последнего элемента страницы, начиная с 1.
+    /// Для пустой страницы возвращается `(0, 0, total)`.
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use sieve::{PagedList, SieveModel, SievePaginate};
+    /// let sieve = SieveModel::new(&Some(2), &Some(10), &None, &None);
+    /// let page = (1..=35).collect::<Vec<_>>().paginate_with(&sieve);
+    /// assert_eq!(page.slice_stats(), (11, 20, 35));
+    /// 
+    /// let sieve = SieveModel::new(&Some(5), &Some(10), &None, &None);
+    /// let page = (1..=35).collect::<Vec<_>>().paginate_with(&sieve);
+    /// assert_eq!(page.slice_stats(), (0, 0, 35));
+    /// ```
+    pub fn slice_stats(&self) -> (u64, u64, u64) {
+        if self.items.is_empty() {
+            return (0, 0, self.total);
+        }
+        let from = self.offset.saturating_add(1);
+        let to = self.offset.saturating_add(self.items.len() as u64);
+        (from, to, self.total)
+    }
+}
+
+/// Сериализация PagedList
+impl<T: Serialize> PagedList<T> {
+    /// Сериализует страницу в JSON напрямую в `writer`
+    /// 
+    /// Элементы пишутся потоково, без промежуточной строки в памяти,
+    /// поэтому метод подходит для очень больших страниц.
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use sieve::PagedList;
+    /// let page = PagedList::new((0..10_000).collect::<Vec<u32>>(), 10_000);
+    /// let mut buffer = Vec::new();
+    /// page.serialize_into(&mut buffer).unwrap();
+    /// 
+    /// let json: serde_json::Value = serde_json::from_slice(&buffer).unwrap();
+    /// assert_eq!(json["total"], 10_000);
+    /// assert_eq!(json["items"].as_array().unwrap().len(), 10_000);
+    /// assert_eq!(json["items"][9_999], 9_999);
+    /// assert!(json.get("offset").is_none());
+    /// 
+    /// // тот же результат, что и при сериализации через serde
+    /// let mut streamed = Vec::new();
+    /// serde_json::to_writer(&mut streamed, &page).unwrap();
+    /// assert_eq!(buffer, streamed);
+    /// ```
+    pub fn serialize_into<W: Write>(&self, writer: W) -> serde_json::Result<()> {
+        serde_json::to_writer(writer, self)
+    }
+}
+
+/// Сериализация без `serde_derive`: `items`, `total` и `meta`, если заданы дополнительные данные
+impl<T: Serialize> Serialize for PagedList<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("PagedList", if self.extra.is_some() { 3 } else { 2 })?;
+        state.serialize_field("items", &self.items)?;
+        state.serialize_field("total", &self.total)?;
+        if let Some(extra) = &self.extra {
+            state.serialize_field("meta", extra)?;
+        }
+        state.end()
+    }
+}
@@ -0,0 +1,61 @@
+/// Параметры страницы, вычисленные по SieveModel и общему количеству записей
+#[derive(Debug, Clone, PartialEq)]
+pub struct Paging {
+    /// Номер страницы
+    page: u64,
+
+    /// Размер страницы
+    page_size: u64,
+
+    /// Смещение первой записи страницы
+    offset: u64,
+
+    /// Общее количество страниц
+    total_pages: u64,
+}
+
+/// Реализация методов для Paging
+impl Paging {
+    pub fn new(page: u64, page_size: u64, offset: u64, total: u64) -> Self {
+        let total_pages = if page_size == 0 {
+            0
+        } else {
+            total.div_ceil(page_size)
+        };
+        Self { page, page_size, offset, total_pages }
+    }
+
+    /// Возвращает номер страницы
+    pub fn page(&self) -> u64 {
+        self.page
+    }
+
+    /// Возвращает размер страницы
+    pub fn page_size(&self) -> u64 {
+        self.page_size
+    }
+
+    /// Возвращает смещение первой записи страницы
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Возвращает общее количество страниц
+    /// 
+    /// Неполная последняя страница учитывается: если записи есть, страниц
+    /// не меньше одной, даже когда размер страницы больше их количества.
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use sieve::Paging;
+    /// assert_eq!(Paging::new(1, 100, 0, 5).total_pages(), 1);
+    /// assert_eq!(Paging::new(1, 100, 0, 0).total_pages(), 0);
+    /// assert_eq!(Paging::new(1, 10, 0, 101).total_pages(), 11);
+    /// assert_eq!(Paging::new(1, 10, 0, 100).total_pages(), 10);
+    /// assert_eq!(Paging::new(1, 2, 0, u64::MAX).total_pages(), u64::MAX / 2 + 1);
+    /// assert_eq!(Paging::new(1, 0, 0, 5).total_pages(), 0);
+    /// ```
+    pub fn total_pages(&self) -> u64 {
+        self.total_pages
+    }
+}
@@ -0,0 +1,35 @@
+use std::fmt;
+use super::filter_operator::FilterOperator;
+
+/// Ошибка проверки SieveModel по схеме
+#[derive(Debug, Clone, PartialEq)]
+pub enum SchemaError {
+    /// Поле отсутствует в схеме
+    UnknownField(String),
+
+    /// Поле есть в схеме, но его имя нельзя подставить в SQL
+    InvalidIdentifier(String),
+
+    /// Оператор нельзя применять к полю этого типа
+    IncompatibleOperator {
+        /// Имя поля
+        field: String,
+
+        /// Оператор фильтрации
+        operator: FilterOperator,
+    },
+}
+
+impl fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SchemaError::UnknownField(field) => write!(f, "Неизвестное поле: {}", field),
+            SchemaError::InvalidIdentifier(field) => write!(f, "Недопустимое имя поля для SQL: {}", field),
+            SchemaError::IncompatibleOperator { field, operator } => {
+                write!(f, "Оператор {} недопустим для поля {}", operator, field)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SchemaError {}
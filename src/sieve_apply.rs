@@ -0,0 +1,70 @@
+use super::field_value::FieldValue;
+use super::paged_list::PagedList;
+use super::sieve_config::SieveConfig;
+use super::sieve_filter::{filter_items, SieveFilter};
+use super::sieve_model::SieveModel;
+use super::sieve_order::{sort_items, SieveOrder};
+use super::sieve_paginate::SievePaginate;
+
+/// Полное применение SieveModel к коллекции: фильтрация, сортировка и пагинация
+/// 
+/// Реализуется автоматически для всех коллекций с [`SieveFilter`],
+/// отдельные трейты остаются доступными для поэтапного применения.
+/// 
+/// # Примеры
+/// ```
+/// # use sieve::{FieldValue, SieveApplicable, SieveConfig, SieveModel};
+/// struct Track { title: String, year: i64 }
+/// 
+/// let config = SieveConfig::new().with_accessor(|track: &Track, name| match name {
+///     "title" => Some(FieldValue::from(track.title.as_str())),
+///     "year" => Some(FieldValue::from(track.year)),
+///     _ => None,
+/// });
+/// let tracks = vec![
+///     Track { title: "Bohemian Rhapsody".to_string(), year: 1975 },
+///     Track { title: "Under Pressure".to_string(), year: 1981 },
+///     Track { title: "Radio Ga Ga".to_string(), year: 1984 },
+///     Track { title: "Killer Queen".to_string(), year: 1974 },
+/// ];
+/// 
+/// let sieve = SieveModel::new(&Some(1), &Some(2), &Some("year>1974".to_string()), &Some("-year".to_string()));
+/// let page = tracks.apply(&sieve, &config);
+/// let titles: Vec<&str> = page.items().iter().map(|track| track.title.as_str()).collect();
+/// assert_eq!(titles, vec!["Radio Ga Ga", "Under Pressure"]);
+/// assert_eq!(page.total(), 3);
+/// ```
+pub trait SieveApplicable<T>: SieveFilter<T> + Sized {
+    /// Применяет фильтры, сортировку и пагинацию именно в таком порядке
+    fn apply(self, sieve: &SieveModel, config: &SieveConfig<T>) -> PagedList<T> {
+        self.filter_with_config(sieve, config)
+            .order_with_config(sieve, config)
+            .paginate_with(sieve)
+    }
+}
+
+impl<T, C: SieveFilter<T>> SieveApplicable<T> for C {}
+
+/// Применяет SieveModel к коллекции в памяти: фильтрация, сортировка,
+/// подсчет общего количества и пагинация - именно в таком порядке
+/// 
+/// # Примеры
+/// ```
+/// # use sieve::{apply_sieve_sorted_paged, FieldValue, SieveModel};
+/// let sieve = SieveModel::new(&Some(1), &Some(2), &Some("value>1".to_string()), &Some("-value".to_string()));
+/// let page = apply_sieve_sorted_paged(vec![3, 1, 5, 2, 4], &sieve, |item, name| {
+///     (name == "value").then(|| FieldValue::from(*item as i64))
+/// });
+/// 
+/// // первая страница при сортировке по убыванию содержит наибольшие элементы
+/// assert_eq!(page.items(), &vec![5, 4]);
+/// assert_eq!(page.total(), 4);
+/// ```
+pub fn apply_sieve_sorted_paged<T, F>(items: Vec<T>, sieve: &SieveModel, field: F) -> PagedList<T>
+where
+    F: Fn(&T, &str) -> Option<FieldValue>,
+{
+    let items = filter_items(items, sieve, &field);
+    let items = sort_items(items, sieve, &field);
+    items.paginate_with(sieve)
+}
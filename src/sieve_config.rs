@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+use std::fmt;
+use super::field_value::FieldValue;
+use super::filter_operator::FilterOperator;
+
+/// Пользовательская функция проверки поля: получает оператор, запись и значения фильтра
+pub type FieldEvaluator<T> = Box<dyn Fn(&FilterOperator, &T, &[String]) -> bool>;
+
+/// Функция получения значения поля записи по имени
+pub type FieldAccessor<T> = Box<dyn Fn(&T, &str) -> Option<FieldValue>>;
+
+/// Настройки применения SieveModel к коллекциям в памяти
+/// 
+/// # Примеры
+/// ```
+/// # use sieve::{FieldValue, SieveConfig, SieveModel, filter_items_with};
+/// // поле "sound" сравнивается без учета гласных вместо стандартной логики
+/// let config = SieveConfig::new().with_evaluator("sound", |_operator, name: &&str, values| {
+///     let key = |s: &str| s.to_lowercase().chars().filter(|c| !"aeiouy".contains(*c)).collect::<String>();
+///     values.iter().any(|value| key(name) == key(value))
+/// });
+/// 
+/// let sieve = SieveModel::new(&None, &None, &Some("sound@=smith".to_string()), &None);
+/// let items = filter_items_with(vec!["Smyth", "Smithson", "Jones"], &sieve, |_, _| None::<FieldValue>, &config);
+/// assert_eq!(items, vec!["Smyth"]);
+/// ```
+pub struct SieveConfig<T> {
+    /// Функция получения значения поля записи
+    accessor: Option<FieldAccessor<T>>,
+
+    /// Пользовательские функции проверки по именам полей
+    evaluators: HashMap<String, FieldEvaluator<T>>,
+}
+
+/// Реализация методов для SieveConfig
+impl<T> SieveConfig<T> {
+    /// Создает настройки по умолчанию
+    pub fn new() -> Self {
+        Self { accessor: None, evaluators: HashMap::new() }
+    }
+
+    /// Задает функцию получения значения поля записи по имени
+    /// 
+    /// Без нее все поля считаются неизвестными: фильтры пропускаются,
+    /// а сортировка не меняет порядок.
+    pub fn with_accessor<F>(mut self, accessor: F) -> Self
+    where
+        F: Fn(&T, &str) -> Option<FieldValue> + 'static,
+    {
+        self.accessor = Some(Box::new(accessor));
+        self
+    }
+
+    /// Возвращает значение поля записи
+    pub fn field(&self, item: &T, name: &str) -> Option<FieldValue> {
+        self.accessor.as_ref().and_then(|accessor| accessor(item, name))
+    }
+
+    /// Регистрирует функцию проверки поля, заменяющую стандартную
+    /// обработку операторов для этого поля
+    /// 
+    /// Для инвертированных термов (`!@=`) результат функции инвертируется.
+    pub fn with_evaluator<F>(mut self, name: &str, evaluator: F) -> Self
+    where
+        F: Fn(&FilterOperator, &T, &[String]) -> bool + 'static,
+    {
+        self.evaluators.insert(name.to_string(), Box::new(evaluator));
+        self
+    }
+
+    /// Возвращает функцию проверки поля, если она зарегистрирована
+    pub fn evaluator(&self, name: &str) -> Option<&FieldEvaluator<T>> {
+        self.evaluators.get(name)
+    }
+}
+
+impl<T> Default for SieveConfig<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for SieveConfig<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SieveConfig")
+            .field("accessor", &self.accessor.is_some())
+            .field("evaluators", &self.evaluators.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
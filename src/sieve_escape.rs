@@ -0,0 +1,202 @@
+/// Разбивает строку по разделителю с учетом экранирования
+///
+/// Обратный слеш экранирует следующий символ: `\,` и `\|` не разделяют
+/// строку, `\\` означает сам обратный слеш. Из частей результата
+/// экранирующие слеши удаляются; одиночный слеш в конце строки
+/// сохраняется как есть.
+///
+/// # Примеры
+/// ```
+/// # use sieve::split_escaped;
+/// assert_eq!(split_escaped("Rock\\, Metal,Jazz", ','), vec!["Rock, Metal", "Jazz"]);
+/// assert_eq!(split_escaped("AC\\|DC|Queen", '|'), vec!["AC|DC", "Queen"]);
+/// assert_eq!(split_escaped("\\|start|end", '|'), vec!["|start", "end"]);
+/// assert_eq!(split_escaped("C:\\\\|D:\\\\", '|'), vec!["C:\\", "D:\\"]);
+/// assert_eq!(split_escaped("tail\\", '|'), vec!["tail\\"]);
+/// assert_eq!(split_escaped("", '|'), vec![""]);
+/// ```
+pub fn split_escaped(input: &str, delimiter: char) -> Vec<String> {
+    split_raw(input, delimiter, false).into_iter().map(unescape_value).collect()
+}
+
+/// Разбивает строку по неэкранированному разделителю, не удаляя экранирование
+///
+/// Части сохраняют обратные слеши, чтобы их можно было разобрать дальше.
+/// Если `brackets` установлен, разделители внутри списка в квадратных
+/// скобках не разделяют строку. Список открывает только `[` сразу после
+/// оператора (`status==[a,b]`, `tag@=*[a,b]`), вложенные скобки внутри
+/// списка учитываются (`tag==[a[1],b]`). Незакрытый список считается
+/// обычным текстом и не объединяет части.
+pub(crate) fn split_raw(input: &str, delimiter: char, brackets: bool) -> Vec<&str> {
+    split_raw_indexed(input, delimiter, brackets).into_iter().map(|(_, part)| part).collect()
+}
+
+/// Разбивает строку так же, как [`split_raw`], возвращая вместе с частями
+/// их смещение в байтах от начала `input`
+pub(crate) fn split_raw_indexed(input: &str, delimiter: char, brackets: bool) -> Vec<(usize, &str)> {
+    split_nested(input, delimiter, |before| brackets && opens_list(before))
+}
+
+/// Разбивает содержимое списка `[...]` по запятым верхнего уровня
+///
+/// Вложенные скобки (`[[a,b],c]`) не разделяют элементы.
+pub(crate) fn split_list(list: &str) -> Vec<&str> {
+    split_nested(list, ',', |_| true).into_iter().map(|(_, part)| part).collect()
+}
+
+/// Возвращает глубину незакрытых квадратных скобок в конце строки
+/// или `None`, если встречается `]` без пары
+pub(crate) fn bracket_depth(value: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '[' => depth += 1,
+            ']' => depth = depth.checked_sub(1)?,
+            _ => {}
+        }
+    }
+    Some(depth)
+}
+
+/// Разбивает строку по разделителю вне скобок
+///
+/// `opens` получает часть перед `[` на верхнем уровне и решает, открывает ли
+/// скобка вложенную группу. Если группа не закрыта, ее скобка считается
+/// обычным символом и строка разбирается заново.
+fn split_nested<F>(input: &str, delimiter: char, opens: F) -> Vec<(usize, &str)>
+where
+    F: Fn(&str) -> bool,
+{
+    let mut literal = Vec::new();
+    loop {
+        let mut result = Vec::new();
+        let mut open = None;
+        let mut depth = 0usize;
+        let mut start = 0;
+        let mut chars = input.char_indices();
+        while let Some((index, c)) = chars.next() {
+            match c {
+                '\\' => {
+                    chars.next();
+                }
+                '[' if depth > 0 => depth += 1,
+                '[' if !literal.contains(&index) && opens(&input[start..index]) => {
+                    open = Some(index);
+                    depth = 1;
+                }
+                ']' if depth > 0 => depth -= 1,
+                c if c == delimiter && depth == 0 => {
+                    result.push((start, &input[start..index]));
+                    start = index + c.len_utf8();
+                }
+                _ => {}
+            }
+        }
+        match open.filter(|_| depth > 0) {
+            Some(index) => literal.push(index),
+            None => {
+                result.push((start, &input[start..]));
+                return result;
+            }
+        }
+    }
+}
+
+/// Проверяет, что часть терма перед `[` заканчивается оператором
+/// (с модификаторами `*` и `!`), то есть скобка открывает список значений
+fn opens_list(before: &str) -> bool {
+    before.trim_end().trim_end_matches(['*', '!']).ends_with(['=', '<', '>'])
+}
+
+/// Экранирует значение для безопасной подстановки в строку фильтров
+///
+/// Обратным слешем экранируются разделители (`,`, `|`), кавычки, обратный
+/// слеш, символы с особым смыслом в значении (`[`, `]`, `:`, `!`, `*`)
+/// и `$` в начале значения, чтобы строка `$null` не означала `NULL`.
+/// Пробелы по краям значения при разборе обрезаются, поэтому такое значение
+/// нужно дополнительно заключить в кавычки.
+///
+/// # Примеры
+/// ```
+/// # use sieve::{escape_value, unescape_value, SieveModel};
+/// assert_eq!(escape_value("Rock, Metal"), "Rock\\, Metal");
+/// assert_eq!(escape_value("AC|DC"), "AC\\|DC");
+/// assert_eq!(escape_value("C:\\"), "C\\:\\\\");
+/// assert_eq!(escape_value("$null"), "\\$null");
+///
+/// // escape_value и unescape_value взаимно обратны
+/// let mut samples: Vec<String> = [
+///     "", "plain", "Rock, Metal", "a|b|c", "\"quoted\"", "'single'", "back\\slash", "tail\\",
+///     "\\\\", "[a,b]", "!Rock", "*Rock", "10:int", "$null", "$null-ish", "Ёлка, ель | сосна", " spaced ",
+/// ].iter().map(|s| s.to_string()).collect();
+///
+/// // псевдослучайные строки из специальных символов
+/// let alphabet: Vec<char> = "ab ,|\"'\\[]:!*=@_-$ё".chars().collect();
+/// let mut seed: u64 = 42;
+/// for _ in 0..200 {
+///     let mut sample = String::new();
+///     for _ in 0..(seed % 12) {
+///         seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+///         sample.push(alphabet[(seed >> 33) as usize % alphabet.len()]);
+///     }
+///     samples.push(sample);
+/// }
+///
+/// for sample in &samples {
+///     assert_eq!(unescape_value(&escape_value(sample)), *sample);
+///
+///     // значение без пробелов по краям переживает разбор фильтра
+///     if !sample.is_empty() && sample.trim() == sample {
+///         let filters = format!("title=={},year>2000", escape_value(sample));
+///         let model = SieveModel::new(&None, &None, &Some(filters), &None);
+///         let terms = model.filters().unwrap();
+///         assert_eq!(terms.len(), 2, "{}", sample);
+///         assert_eq!(terms[0].values(), vec![sample.clone()], "{}", sample);
+///         assert!(!terms[0].typed_values()[0].is_null(), "{}", sample);
+///     }
+/// }
+/// ```
+pub fn escape_value(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    for (index, c) in value.chars().enumerate() {
+        if matches!(c, '\\' | ',' | '|' | '"' | '\'' | '[' | ']' | ':' | '!' | '*') || (index == 0 && c == '$') {
+            result.push('\\');
+        }
+        result.push(c);
+    }
+    result
+}
+
+/// Удаляет экранирование из значения фильтра так же, как при разборе DSL
+///
+/// Обратный слеш экранирует следующий символ (`\,`, `\|`, `\"`, `\\`
+/// и любой другой) и удаляется. Одиночный слеш в конце строки сохраняется.
+///
+/// # Примеры
+/// ```
+/// # use sieve::unescape_value;
+/// assert_eq!(unescape_value("Rock\\, Metal"), "Rock, Metal");
+/// assert_eq!(unescape_value("AC\\|DC"), "AC|DC");
+/// assert_eq!(unescape_value("12\\\" vinyl"), "12\" vinyl");
+/// assert_eq!(unescape_value("C:\\\\temp"), "C:\\temp");
+/// assert_eq!(unescape_value("\\@field"), "@field");
+/// assert_eq!(unescape_value("tail\\"), "tail\\");
+/// assert_eq!(unescape_value("tail\\\\"), "tail\\");
+/// assert_eq!(unescape_value(""), "");
+/// ```
+pub fn unescape_value(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            result.push(chars.next().unwrap_or('\\'));
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
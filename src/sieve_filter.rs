@@ -0,0 +1,102 @@
+use super::compiled_filter::CompiledFilter;
+use super::field_value::FieldValue;
+use super::filter_term::FilterTerm;
+use super::sieve_config::SieveConfig;
+use super::sieve_model::SieveModel;
+use super::sieveable::Sieveable;
+
+/// Фильтрация коллекции в памяти по условиям SieveModel
+pub trait SieveFilter<T> {
+    /// Возвращает элементы, удовлетворяющие всем фильтрам
+    /// 
+    /// Значения полей берутся из функции, заданной в [`SieveConfig::with_accessor`].
+    fn filter_with_config(self, sieve: &SieveModel, config: &SieveConfig<T>) -> Vec<T>;
+
+    /// Возвращает элементы, удовлетворяющие всем фильтрам
+    /// 
+    /// Значения полей берутся из [`Sieveable::field`].
+    fn filter_with(self, sieve: &SieveModel) -> Vec<T>
+    where
+        T: Sieveable;
+}
+
+impl<T> SieveFilter<T> for Vec<T> {
+    fn filter_with_config(self, sieve: &SieveModel, config: &SieveConfig<T>) -> Vec<T> {
+        filter_items_with(self, sieve, |item, name| config.field(item, name), config)
+    }
+
+    fn filter_with(self, sieve: &SieveModel) -> Vec<T>
+    where
+        T: Sieveable,
+    {
+        filter_items(self, sieve, |item, name| item.field(name))
+    }
+}
+
+/// Оставляет только элементы, удовлетворяющие всем фильтрам SieveModel
+/// 
+/// `field` возвращает значение поля элемента по имени или `None`,
+/// если такого поля нет.
+/// 
+/// # Примеры
+/// ```
+/// # use sieve::{filter_items, FieldValue, SieveModel};
+/// let sieve = SieveModel::new(&None, &None, &Some("value>2".to_string()), &None);
+/// let items = filter_items(vec![1, 2, 3, 4], &sieve, |item, name| {
+///     (name == "value").then(|| FieldValue::from(*item as i64))
+/// });
+/// assert_eq!(items, vec![3, 4]);
+/// ```
+pub fn filter_items<T, F>(items: Vec<T>, sieve: &SieveModel, field: F) -> Vec<T>
+where
+    F: Fn(&T, &str) -> Option<FieldValue>,
+{
+    filter_items_with(items, sieve, field, &SieveConfig::new())
+}
+
+/// Оставляет только элементы, удовлетворяющие всем фильтрам SieveModel,
+/// с учетом пользовательских функций проверки из настроек
+/// 
+/// См. [`SieveConfig::with_evaluator`].
+pub fn filter_items_with<T, F>(items: Vec<T>, sieve: &SieveModel, field: F, config: &SieveConfig<T>) -> Vec<T>
+where
+    F: Fn(&T, &str) -> Option<FieldValue>,
+{
+    let filter = CompiledFilter::new(sieve);
+    items
+        .into_iter()
+        .filter(|item| filter.matches_with(item, &field, config))
+        .collect()
+}
+
+/// Фильтрует элементы так же, как [`filter_items`], и дополнительно возвращает
+/// для каждого терма количество элементов, которые удовлетворяют ему по отдельности
+/// 
+/// Терм с нулевым количеством сразу указывает, почему результат пуст.
+/// 
+/// # Примеры
+/// ```
+/// # use sieve::{filter_items_diagnostic, FieldValue, SieveModel};
+/// let sieve = SieveModel::new(&None, &None, &Some("value>1,value<10,value==42".to_string()), &None);
+/// let (items, counts) = filter_items_diagnostic(vec![1, 2, 3, 4], &sieve, |item, name| {
+///     (name == "value").then(|| FieldValue::from(*item as i64))
+/// });
+/// assert!(items.is_empty());
+/// 
+/// let counts: Vec<(String, usize)> = counts.iter().map(|(term, count)| (term.values()[0].clone(), *count)).collect();
+/// assert_eq!(counts, vec![("1".to_string(), 3), ("10".to_string(), 4), ("42".to_string(), 0)]);
+/// ```
+pub fn filter_items_diagnostic<T, F>(items: Vec<T>, sieve: &SieveModel, field: F) -> (Vec<T>, Vec<(FilterTerm, usize)>)
+where
+    F: Fn(&T, &str) -> Option<FieldValue>,
+{
+    let config = SieveConfig::new();
+    let filter = CompiledFilter::new(sieve);
+    let counts = filter.match_counts(&items, &field, &config);
+    let diagnostics = filter.terms().into_iter().zip(counts).collect();
+    let items = items
+        .into_iter()
+        .filter(|item| filter.matches_with(item, &field, &config))
+        .collect();
+    (items, diagnostics)
+}
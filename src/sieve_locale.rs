@@ -0,0 +1,117 @@
+use std::cmp::Ordering;
+
+/// Языки, для которых есть правила сортировки и приведения регистра
+/// 
+/// Правила задаются встроенной таблицей, а не полными данными CLDR:
+/// особые буквы алфавита для `da`, `nb`/`nn`/`no`, `sv`, `fi`, `es`, `tr`, `az`
+/// и сравнение без диакритики (DIN 5007-1) для `de` и `en`.
+pub(crate) const SUPPORTED_LANGUAGES: [&str; 11] = ["az", "da", "de", "en", "es", "fi", "nb", "nn", "no", "sv", "tr"];
+
+/// Проверяет, что для языка локали есть встроенные правила
+/// 
+/// Локали с расширениями Unicode (`de-u-co-phonebk`) или вариантами
+/// (`de@collation=phonebook`) задают другой порядок и не поддерживаются.
+pub(crate) fn is_supported(locale: &str) -> bool {
+    let locale = locale.to_lowercase();
+    !locale.contains("-u-") && !locale.contains("_u_") && !locale.contains('@')
+        && SUPPORTED_LANGUAGES.contains(&language(&locale).as_str())
+}
+
+/// Приводит строку к нижнему регистру с учетом локали
+///
+/// Без функции `unicode` и для локалей без особых правил используется
+/// языконезависимое `to_lowercase`.
+pub(crate) fn fold_case(value: &str, locale: Option<&str>) -> String {
+    #[cfg(feature = "unicode")]
+    if matches!(locale.map(language).as_deref(), Some("tr" | "az")) {
+        return value
+            .chars()
+            .map(|c| match c {
+                'I' => "ı".to_string(),
+                'İ' => "i".to_string(),
+                c => c.to_lowercase().to_string(),
+            })
+            .collect();
+    }
+    #[cfg(not(feature = "unicode"))]
+    let _ = locale;
+    value.to_lowercase()
+}
+
+/// Сравнивает строки для сортировки с учетом локали
+///
+/// Без локали, для неподдерживаемой локали и без функции `unicode`
+/// строки сравниваются побайтово.
+/// С локалью сначала сравниваются буквы без диакритики и регистра
+/// (с особыми буквами алфавита языка), затем диакритика, затем регистр.
+pub(crate) fn collate(a: &str, b: &str, locale: Option<&str>) -> Ordering {
+    #[cfg(feature = "unicode")]
+    if let Some(locale) = locale.filter(|locale| is_supported(locale)) {
+        let language = language(locale);
+        let primary = |value: &str| value.chars().flat_map(|c| primary_weights(c, &language)).collect::<Vec<u32>>();
+        return primary(a)
+            .cmp(&primary(b))
+            .then_with(|| fold_case(a, Some(&language)).cmp(&fold_case(b, Some(&language))))
+            .then_with(|| b.cmp(a));
+    }
+    #[cfg(not(feature = "unicode"))]
+    let _ = locale;
+    a.cmp(b)
+}
+
+/// Возвращает основной язык локали (`sv-SE` и `sv_SE` - `sv`)
+fn language(locale: &str) -> String {
+    locale.split(['-', '_']).next().unwrap_or_default().to_lowercase()
+}
+
+/// Возвращает основные веса символа: буквы без диакритики и регистра
+///
+/// Особые буквы алфавита языка получают собственный вес после базовой буквы
+/// (`ñ` в испанском идет после `n`, `ä` в шведском - после `z`).
+#[cfg(feature = "unicode")]
+fn primary_weights(c: char, language: &str) -> Vec<u32> {
+    let lower = c.to_lowercase().next().unwrap_or(c);
+    let tailored = match (language, lower) {
+        ("sv" | "fi", 'å') => Some(('z', 1)),
+        ("sv" | "fi", 'ä' | 'æ') => Some(('z', 2)),
+        ("sv" | "fi", 'ö' | 'ø') => Some(('z', 3)),
+        ("da" | "nb" | "nn" | "no", 'æ' | 'ä') => Some(('z', 1)),
+        ("da" | "nb" | "nn" | "no", 'ø' | 'ö') => Some(('z', 2)),
+        ("da" | "nb" | "nn" | "no", 'å') => Some(('z', 3)),
+        ("es", 'ñ') => Some(('n', 1)),
+        ("tr" | "az", 'ç') => Some(('c', 1)),
+        ("tr" | "az", 'ğ') => Some(('g', 1)),
+        ("tr" | "az", 'ı') => Some(('h', 1)),
+        ("tr" | "az", 'ö') => Some(('o', 1)),
+        ("tr" | "az", 'ş') => Some(('s', 1)),
+        ("tr" | "az", 'ü') => Some(('u', 1)),
+        _ => None,
+    };
+    let weight = |base: char, rank: u32| (base as u32) * 4 + rank;
+    if let Some((base, rank)) = tailored {
+        return vec![weight(base, rank)];
+    }
+
+    let base = match lower {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+        'ç' | 'ć' | 'č' => "c",
+        'ď' | 'đ' => "d",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ė' | 'ę' | 'ě' => "e",
+        'ğ' => "g",
+        'ì' | 'í' | 'î' | 'ï' | 'ī' | 'į' | 'ı' => "i",
+        'ł' => "l",
+        'ñ' | 'ń' | 'ň' => "n",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ő' => "o",
+        'ř' => "r",
+        'ś' | 'š' | 'ş' => "s",
+        'ť' => "t",
+        'ù' | 'ú' | 'û' | 'ü' | 'ū' | 'ů' | 'ű' => "u",
+        'ý' | 'ÿ' => "y",
+        'ź' | 'ż' | 'ž' => "z",
+        'ß' => "ss",
+        'æ' => "ae",
+        'œ' => "oe",
+        _ => return vec![weight(lower, 0)],
+    };
+    base.chars().map(|c| weight(c, 0)).collect()
+}
@@ -1,7 +1,16 @@
 use std::str::FromStr;
+use super::paging::Paging;
+use super::sieve_escape::{escape_value, split_raw_indexed};
+use super::sieve_locale::is_supported;
+use super::sieve_parse_error::SieveParseError;
 use super::sieve_regex::COMMA_PATTERN;
-use super::filter_term::FilterTerm;
+use super::sieve_schema::SieveSchema;
+use super::filter_operator::FilterOperator;
+use super::filter_term::{FilterTerm, NULL_VALUE};
+use super::sort_order::SortOrder;
 use super::sort_term::SortTerm;
+#[cfg(feature = "serde")]
+use serde::Deserialize;
 
 
 /// Модель для обработки параметров запроса с поддержкой фильтрации, сортировки и пагинации
@@ -10,44 +19,51 @@ use super::sort_term::SortTerm;
 /// 
 /// ## Фильтрация
 /// ```
+/// # use sieve::SieveModel;
 /// let model = SieveModel::new(
-///     Some(1),
-///     Some(10),
-///     Some("title@=Rock,artist==Queen".to_string()),
-///     Some("title".to_string())
+///     &Some(1),
+///     &Some(10),
+///     &Some("title@=Rock,artist==Queen".to_string()),
+///     &Some("title".to_string())
 /// );
+/// assert_eq!(model.filters().unwrap().len(), 2);
 /// ```
 /// 
 /// ## Сортировка
 /// ```
+/// # use sieve::SieveModel;
 /// let model = SieveModel::new(
-///     Some(1),
-///     Some(10),
-///     None,
-///     Some("-created_time,title".to_string())
+///     &Some(1),
+///     &Some(10),
+///     &None,
+///     &Some("-created_time,title".to_string())
 /// );
+/// assert_eq!(model.sorts().unwrap().len(), 2);
 /// ```
 /// 
 /// ## Пагинация
 /// ```
+/// # use sieve::SieveModel;
 /// let model = SieveModel::new(
-///     Some(2),
-///     Some(20),
-///     None,
-///     None
-/// };
+///     &Some(2),
+///     &Some(20),
+///     &None,
+///     &None
+/// );
+/// assert_eq!(model.page(), 2);
 /// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Deserialize), serde(from = "SieveQuery"))]
 pub struct SieveModel {
-    /// Номер страницы (начиная с 1)
+    /// Номер страницы (начиная с 1 или с 0, см. `zero_based_pages`)
     /// 
     /// # Примеры
     /// - `1` - первая страница
     /// - `2` - вторая страница
     /// 
     /// # Значение по умолчанию
-    /// Если не указано, используется значение 1
-    page: u64,
+    /// Если не указано, используется первая страница
+    page: Option<u64>,
 
     /// Размер страницы (количество элементов на странице)
     /// 
@@ -70,8 +86,12 @@ pub struct SieveModel {
     /// - `поле<значение` - меньше
     /// - `поле_=значение` - начинается с
     /// - `поле_-=значение` - заканчивается на
-    /// - `(поле1|поле2)@=значение` - поиск по нескольким полям
+    /// - `поле==$null` - поле не заполнено (`IS NULL`), `поле!=$null` - заполнено
+    /// - `(поле1|поле2)@=значение` - поиск по нескольким полям (достаточно совпадения в одном)
+    /// - `(поле1|поле2)!=значение` - исключение по нескольким полям (должны отличаться все)
     /// - `поле@=значение1|значение2` - поиск по нескольким значениям
+    /// - `поле==[значение1,значение2]` - список значений (аналог `IN`)
+    /// - `число<=поле<=число` - диапазон (цепочка сравнений)
     /// 
     /// Можно комбинировать условия через запятую:
     /// `поле1@=значение1,поле2==значение2`
@@ -83,10 +103,19 @@ pub struct SieveModel {
     /// - `<` - меньше
     /// - `>=` - больше или равно
     /// - `<=` - меньше или равно
-    /// - `@=` - содержит (регистронезависимый поиск)
+    /// - `@=` - содержит
     /// - `_=` - начинается с
     /// - `_-=` - заканчивается на
     /// 
+    /// # Модификаторы
+    /// - `!` перед оператором (`!@=`, `!_=`, `!_-=`) - отрицание условия
+    /// - `*` в начале значения (`поле==*значение`) - сравнение без учета регистра
+    /// - `!` в начале значения для `@=`, `_=` и `_-=` (`поле@=!значение`) - отрицание условия
+    /// 
+    /// Модификаторы значения `*` и `!` можно указывать в любом порядке
+    /// (`поле@=*!значение` и `поле@=!*значение` равнозначны), каждый - один раз.
+    /// Экранированные `\*` и `\!` - обычные символы значения.
+    /// 
     /// # Примеры
     /// - `title@=Rock` - поиск треков, содержащих "Rock" в названии
     /// - `artist==Queen` - поиск треков исполнителя "Queen"
@@ -96,19 +125,28 @@ pub struct SieveModel {
     /// # Экранирование
     /// Для экранирования специальных символов используйте обратный слеш:
     /// - `title@=Rock\, Metal` - поиск треков, содержащих "Rock, Metal" в названии
+    /// - `\@field==значение` - фильтрация по полю с именем `@field`
+    /// - `status==""` - значение в кавычках сохраняется как есть (здесь - пустая строка)
+    /// - `name>'M'` - значение в одинарных кавычках, то же самое, что `name>M`
     /// 
     /// # Негация
-    /// Для инвертирования условия добавьте `!` перед оператором:
-    /// - `title@=!Rock` - поиск треков, не содержащих "Rock" в названии
+    /// Для инвертирования условия добавьте `!` перед оператором или значением
+    /// (для `@=`, `_=` и `_-=`):
+    /// - `title!@=Rock` - поиск треков, не содержащих "Rock" в названии
+    /// - `title@=!Rock` - то же самое
+    /// - `title@=\!Rock` - поиск треков, содержащих "!Rock" в названии
+    /// - `title!@=!Rock` - двойное отрицание, то же самое, что `title@=Rock`
     /// 
     /// # Регистронезависимый поиск
-    /// Для регистронезависимого поиска добавьте `*` после оператора:
+    /// По умолчанию сравнение учитывает регистр. Для регистронезависимого
+    /// поиска добавьте `*` после любого оператора:
     /// - `title@=*Rock` - поиск треков, содержащих "Rock" в названии (регистронезависимый)
+    /// - `artist==*queen` - поиск треков исполнителя "Queen" в любом регистре
     filters: Option<Vec<FilterTerm>>,
     // pub filters: Option<String>,
 
     /// Условие сортировки записей
-    /// Формат: `поле` или `-поле` для сортировки по убыванию
+    /// Формат: `поле` или `-поле` для сортировки по убыванию (`+поле` - явно по возрастанию)
     /// Можно указать несколько полей через запятую
     /// 
     /// # Формат
@@ -127,9 +165,45 @@ pub struct SieveModel {
     /// - `duration` - длительность
     /// - `created_time` - дата создания
     /// - `file_size` - размер файла
-    sorts: Option<Vec<SortTerm>>
+    sorts: Option<Vec<SortTerm>>,
     // pub sorts: Option<String>
 
+    /// Флаг выборки только уникальных записей (`SELECT DISTINCT`)
+    /// 
+    /// # Значение по умолчанию
+    /// Если не указано, используется значение `false`
+    distinct: bool,
+
+    /// Флаг нумерации страниц с нуля (`page=0` - первая страница)
+    /// 
+    /// # Значение по умолчанию
+    /// Если не указано, используется значение `false` (нумерация с 1)
+    zero_based_pages: bool,
+
+    /// Флаг наследования направления сортировки
+    /// 
+    /// Если включен и первый терм сортировки указан с `-`, то следующие
+    /// термы без префикса тоже сортируются по убыванию. Префикс `+`
+    /// явно задает сортировку по возрастанию.
+    /// 
+    /// # Значение по умолчанию
+    /// Если не указано, используется значение `false`
+    inherit_sort_order: bool,
+
+    /// Флаг включения мягко удаленных записей
+    /// 
+    /// # Значение по умолчанию
+    /// Если не указано, используется значение `false`
+    include_deleted: bool,
+
+    /// Локаль для приведения регистра и сортировки строк в памяти (`sv-SE`, `tr`)
+    /// 
+    /// Учитывается при включенной функции `unicode`; хранится только
+    /// поддерживаемая локаль (см. `with_locale`).
+    /// 
+    /// # Значение по умолчанию
+    /// Если не указано, используются языконезависимые правила
+    locale: Option<String>,
 }
 
 /// Реализация модели SieveModel
@@ -139,20 +213,287 @@ impl SieveModel {
     /// 
     /// # Примеры
     /// ```
+    /// # use sieve::SieveModel;
     /// let model = SieveModel::new(
-    ///     Some(1),
-    ///     Some(10),
-    ///     None,
-    ///     None
+    ///     &Some(1),
+    ///     &Some(10),
+    ///     &None,
+    ///     &None
     /// );
+    /// assert_eq!(model.page_size(), 10);
     /// ```
     pub fn new(page: &Option<u64>, page_size: &Option<u64>, filters: &Option<String>, sorts: &Option<String>) -> Self {
         Self {
-            page: page.unwrap_or(1),
+            page: *page,
             page_size: page_size.unwrap_or(100),
-            filters: SieveModel::parse_filters(&filters),
-            sorts: SieveModel::parse_sorts(&sorts)
+            filters: SieveModel::parse_filters(filters),
+            sorts: SieveModel::parse_sorts(sorts),
+            distinct: false,
+            zero_based_pages: false,
+            inherit_sort_order: false,
+            include_deleted: false,
+            locale: None,
+        }
+    }
+
+    /// Создает SieveModel так же, как [`SieveModel::new`], и возвращает
+    /// ошибки разбора некорректных термов вместо того, чтобы молча их отбрасывать
+    /// 
+    /// Модель содержит все корректные термы. Каждая ошибка хранит диапазон
+    /// байтов ошибочного сегмента в строке своего параметра.
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use sieve::SieveModel;
+    /// let filters = "title@=Rock,,artist==x";
+    /// let (model, errors) = SieveModel::parse_verbose(&None, &None, &Some(filters.to_string()), &Some("-year, ,title".to_string()));
+    /// assert_eq!(model.filters().unwrap().len(), 2);
+    /// assert_eq!(model.sorts().unwrap().len(), 2);
+    /// 
+    /// assert_eq!(errors.len(), 2);
+    /// assert_eq!(errors[0].parameter(), "filters");
+    /// assert_eq!(errors[0].span(), 12..12);
+    /// assert_eq!(&filters[..errors[0].span().start], "title@=Rock,");
+    /// assert_eq!(errors[1].parameter(), "sorts");
+    /// assert_eq!(errors[1].span(), 7..7);
+    /// 
+    /// // некорректная цепочка сравнений подсвечивается целиком
+    /// let filters = "year>2000, 10<x>5";
+    /// let (_, errors) = SieveModel::parse_verbose(&None, &None, &Some(filters.to_string()), &None);
+    /// assert_eq!(&filters[errors[0].span()], "10<x>5");
+    /// ```
+    pub fn parse_verbose(page: &Option<u64>, page_size: &Option<u64>, filters: &Option<String>, sorts: &Option<String>) -> (Self, Vec<SieveParseError>) {
+        let mut errors = Vec::new();
+        let filters = SieveModel::parse_filters_verbose(filters, &mut errors);
+        let sorts = SieveModel::parse_sorts_verbose(sorts, &mut errors);
+        let model = Self { filters, sorts, ..SieveModel::new(page, page_size, &None, &None) };
+        (model, errors)
+    }
+
+    /// Создает SieveModel, если все термы фильтрации и сортировки корректны
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use sieve::SieveModel;
+    /// assert!(SieveModel::try_new(&None, &None, &Some("title@=Rock".to_string()), &None).is_ok());
+    /// 
+    /// let errors = SieveModel::try_new(&None, &None, &Some("title@=Rock,,artist==x".to_string()), &None).unwrap_err();
+    /// assert_eq!(errors[0].span(), 12..12);
+    /// assert_eq!(errors[0].to_string(), "filters [12..12]: Filter is empty");
+    /// ```
+    pub fn try_new(page: &Option<u64>, page_size: &Option<u64>, filters: &Option<String>, sorts: &Option<String>) -> Result<Self, Vec<SieveParseError>> {
+        let (model, errors) = SieveModel::parse_verbose(page, page_size, filters, sorts);
+        if errors.is_empty() {
+            Ok(model)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Создает SieveModel из готовых термов без разбора строк фильтрации и сортировки
+    /// 
+    /// Пустые списки равнозначны отсутствию параметра.
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use sieve::{FilterOperator, FilterTerm, SieveModel, SortOrder, SortTerm};
+    /// let model = SieveModel::from_terms(
+    ///     &Some(2),
+    ///     &None,
+    ///     vec![FilterTerm::new(vec!["year".to_string()], FilterOperator::GreaterThan, vec!["2000".to_string()], false)],
+    ///     vec![SortTerm::new("year", SortOrder::Descending)]
+    /// );
+    /// assert_eq!(model.page(), 2);
+    /// assert_eq!(model.page_size(), 100);
+    /// assert_eq!(model.filters().unwrap()[0].operator(), FilterOperator::GreaterThan);
+    /// assert_eq!(model.sorts().unwrap()[0].name(), "year");
+    /// 
+    /// let model = SieveModel::from_terms(&None, &None, vec![], vec![]);
+    /// assert!(model.filters().is_none());
+    /// assert!(model.sorts().is_none());
+    /// ```
+    pub fn from_terms(page: &Option<u64>, page_size: &Option<u64>, filters: Vec<FilterTerm>, sorts: Vec<SortTerm>) -> Self {
+        let mut model = SieveModel::new(page, page_size, &None, &None);
+        model.filters = Some(filters).filter(|filters| !filters.is_empty());
+        model.sorts = Some(sorts).filter(|sorts| !sorts.is_empty());
+        model
+    }
+
+    /// Возвращает копию модели с другими термами, сохраняя остальные параметры
+    /// 
+    /// Пустые списки равнозначны отсутствию параметра.
+    pub(crate) fn with_terms(&self, filters: Vec<FilterTerm>, sorts: Vec<SortTerm>) -> Self {
+        Self {
+            filters: Some(filters).filter(|filters| !filters.is_empty()),
+            sorts: Some(sorts).filter(|sorts| !sorts.is_empty()),
+            ..self.clone()
+        }
+    }
+
+    /// Задает размер страницы
+    pub(crate) fn with_page_size(mut self, page_size: u64) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Задает флаг `distinct` из значения параметра запроса
+    /// 
+    /// Значения `true`, `1`, `yes` и `on` (без учета регистра) включают флаг,
+    /// любые другие значения и отсутствие параметра - выключают.
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use sieve::SieveModel;
+    /// let model = SieveModel::new(&None, &None, &None, &None).with_distinct(&Some("true".to_string()));
+    /// assert!(model.distinct());
+    /// 
+    /// let model = SieveModel::new(&None, &None, &None, &None).with_distinct(&Some("0".to_string()));
+    /// assert!(!model.distinct());
+    /// 
+    /// let model = SieveModel::new(&None, &None, &None, &None).with_distinct(&None);
+    /// assert!(!model.distinct());
+    /// ```
+    pub fn with_distinct(mut self, distinct: &Option<String>) -> Self {
+        self.distinct = SieveModel::parse_flag(distinct);
+        self
+    }
+
+    /// Включает нумерацию страниц с нуля
+    /// 
+    /// В этом режиме `page=0` - первая страница, а смещение равно `page * page_size`.
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use sieve::SieveModel;
+    /// let model = SieveModel::new(&Some(0), &Some(10), &None, &None).with_zero_based_pages(true);
+    /// assert_eq!(model.offset(), 0);
+    /// let model = SieveModel::new(&Some(2), &Some(10), &None, &None).with_zero_based_pages(true);
+    /// assert_eq!(model.offset(), 20);
+    /// let model = SieveModel::new(&None, &Some(10), &None, &None).with_zero_based_pages(true);
+    /// assert_eq!(model.page(), 0);
+    /// 
+    /// // по умолчанию нумерация с 1
+    /// let model = SieveModel::new(&Some(2), &Some(10), &None, &None);
+    /// assert_eq!(model.offset(), 10);
+    /// let model = SieveModel::new(&None, &Some(10), &None, &None);
+    /// assert_eq!(model.page(), 1);
+    /// ```
+    pub fn with_zero_based_pages(mut self, zero_based_pages: bool) -> Self {
+        self.zero_based_pages = zero_based_pages;
+        self
+    }
+
+    /// Задает флаг `include_deleted` из значения параметра запроса
+    /// 
+    /// Значения разбираются так же, как в [`SieveModel::with_distinct`].
+    pub fn with_include_deleted(mut self, include_deleted: &Option<String>) -> Self {
+        self.include_deleted = SieveModel::parse_flag(include_deleted);
+        self
+    }
+
+    /// Задает локаль из значения параметра запроса
+    /// 
+    /// Поддерживаются только языки со встроенными правилами: `da`, `de`,
+    /// `en`, `es`, `fi`, `nb`, `nn`, `no`, `sv`, `tr` и `az` (регион, например
+    /// `sv-SE`, допускается). Полные правила Unicode (CLDR) не используются,
+    /// поэтому остальные локали (`cs`, `lt`, `de-u-co-phonebk` как телефонная
+    /// книга и т. п.) отбрасываются, как и пустое значение: строки
+    /// сравниваются побайтово.
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use sieve::{sort_items, FieldValue, SieveModel};
+    /// let words = vec!["Zebra", "Äpple", "Apple", "Öl"];
+    /// let sort = |locale: Option<&str>| {
+    ///     let sieve = SieveModel::new(&None, &None, &None, &Some("word".to_string()))
+    ///         .with_locale(&locale.map(str::to_string));
+    ///     sort_items(words.clone(), &sieve, |word, _| Some(FieldValue::from(*word)))
+    /// };
+    /// 
+    /// if cfg!(feature = "unicode") {
+    ///     // в шведском `ä` и `ö` - отдельные буквы после `z`
+    ///     assert_eq!(sort(Some("sv-SE")), vec!["Apple", "Zebra", "Äpple", "Öl"]);
+    ///     // в немецком `ä` сортируется вместе с `a`
+    ///     assert_eq!(sort(Some("de-DE")), vec!["Apple", "Äpple", "Öl", "Zebra"]);
+    /// }
+    /// // неподдерживаемая локаль отбрасывается
+    /// assert_eq!(sort(Some("cs-CZ")), vec!["Apple", "Zebra", "Äpple", "Öl"]);
+    /// let sieve = SieveModel::new(&None, &None, &None, &None).with_locale(&Some("cs-CZ".to_string()));
+    /// assert_eq!(sieve.locale(), None);
+    /// let sieve = SieveModel::new(&None, &None, &None, &None).with_locale(&Some("de-u-co-phonebk".to_string()));
+    /// assert_eq!(sieve.locale(), None);
+    /// // без локали строки сравниваются побайтово
+    /// assert_eq!(sort(None), vec!["Apple", "Zebra", "Äpple", "Öl"]);
+    /// 
+    /// // в турецком `I` в нижнем регистре - `ı`, а `İ` - `i`
+    /// # use sieve::filter_items;
+    /// let sieve = SieveModel::new(&None, &None, &Some("city==*istanbul".to_string()), &None)
+    ///     .with_locale(&Some("tr".to_string()));
+    /// let cities = filter_items(vec!["İSTANBUL", "ISTANBUL"], &sieve, |city, _| Some(FieldValue::from(*city)));
+    /// if cfg!(feature = "unicode") {
+    ///     assert_eq!(cities, vec!["İSTANBUL"]);
+    /// } else {
+    ///     assert_eq!(cities, vec!["ISTANBUL"]);
+    /// }
+    /// 
+    /// let sieve = SieveModel::new(&None, &None, &None, &None).with_locale(&Some(" ".to_string()));
+    /// assert_eq!(sieve.locale(), None);
+    /// ```
+    pub fn with_locale(mut self, locale: &Option<String>) -> Self {
+        self.locale = locale.as_ref().map(|locale| locale.trim().to_string()).filter(|locale| is_supported(locale));
+        self
+    }
+
+    /// Ограничивает выборку записями, которые не удалены мягко
+    /// 
+    /// Добавляет фильтр `поле==$null`, если не установлен флаг `include_deleted`.
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use sieve::{SieveModel, SqlDialect};
+    /// let model = SieveModel::new(&None, &None, &Some("title@=Rock".to_string()), &None)
+    ///     .with_soft_delete_scope("deleted_at");
+    /// let filters = model.filters().unwrap();
+    /// assert_eq!(filters[1].names(), vec!["deleted_at"]);
+    /// assert_eq!(filters[1].values(), vec!["$null"]);
+    /// assert_eq!(
+    ///     model.describe_sql(SqlDialect::Postgres).where_clause(),
+    ///     Some(r#""title" LIKE $1 ESCAPE '\' AND "deleted_at" IS NULL"#.to_string())
+    /// );
+    /// 
+    /// let model = SieveModel::new(&None, &None, &Some("title@=Rock".to_string()), &None)
+    ///     .with_include_deleted(&Some("true".to_string()))
+    ///     .with_soft_delete_scope("deleted_at");
+    /// assert!(model.include_deleted());
+    /// assert_eq!(model.filters().unwrap().len(), 1);
+    /// ```
+    pub fn with_soft_delete_scope(mut self, column: &str) -> Self {
+        if !self.include_deleted {
+            let term = FilterTerm::new(vec![column.to_string()], FilterOperator::Equals, vec![NULL_VALUE.to_string()], false);
+            self.filters.get_or_insert_with(Vec::new).push(term);
         }
+        self
+    }
+
+    /// Включает наследование направления сортировки от первого терма
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use sieve::{SieveModel, SortOrder};
+    /// let sorts = Some("-created_time,title,+artist".to_string());
+    /// let orders = |model: SieveModel| model.sorts().unwrap().iter().map(|sort| sort.order()).collect::<Vec<_>>();
+    /// 
+    /// // по умолчанию каждый терм без префикса сортируется по возрастанию
+    /// let model = SieveModel::new(&None, &None, &None, &sorts);
+    /// assert_eq!(orders(model), vec![SortOrder::Descending, SortOrder::Ascending, SortOrder::Ascending]);
+    /// 
+    /// // с наследованием `title` сортируется по убыванию, а `+artist` - по возрастанию
+    /// let model = SieveModel::new(&None, &None, &None, &sorts).with_inherit_sort_order(true);
+    /// assert_eq!(orders(model), vec![SortOrder::Descending, SortOrder::Descending, SortOrder::Ascending]);
+    /// ```
+    pub fn with_inherit_sort_order(mut self, inherit_sort_order: bool) -> Self {
+        self.inherit_sort_order = inherit_sort_order;
+        self
     }
 
     /// Возвращает номер страницы
@@ -161,7 +502,21 @@ impl SieveModel {
     /// - `1` - первая страница
     /// - `2` - вторая страница
     pub fn page(&self) -> u64 {
-        self.page
+        self.page.unwrap_or(self.first_page())
+    }
+
+    /// Возвращает номер первой страницы: 0 или 1 в зависимости от режима нумерации
+    pub fn first_page(&self) -> u64 {
+        if self.zero_based_pages {
+            0
+        } else {
+            1
+        }
+    }
+
+    /// Возвращает флаг нумерации страниц с нуля
+    pub fn zero_based_pages(&self) -> bool {
+        self.zero_based_pages
     }
 
     /// Возвращает размер страницы
@@ -173,6 +528,245 @@ impl SieveModel {
         self.page_size
     }
 
+    /// Возвращает локаль для приведения регистра и сортировки строк
+    pub fn locale(&self) -> Option<String> {
+        self.locale.clone()
+    }
+
+    /// Возвращает флаг включения мягко удаленных записей
+    pub fn include_deleted(&self) -> bool {
+        self.include_deleted
+    }
+
+    /// Возвращает флаг выборки только уникальных записей
+    pub fn distinct(&self) -> bool {
+        self.distinct
+    }
+
+    /// Возвращает смещение первой записи страницы
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use sieve::SieveModel;
+    /// let model = SieveModel::new(&Some(3), &Some(20), &None, &None);
+    /// assert_eq!(model.offset(), 40);
+    /// ```
+    pub fn offset(&self) -> u64 {
+        self.page().saturating_sub(self.first_page()).saturating_mul(self.page_size)
+    }
+
+    /// Возвращает количество записей, которое придется прочитать источнику
+    /// данных для выдачи страницы (`offset + limit`)
+    /// 
+    /// Позволяет обнаружить глубокую пагинацию и отклонить запрос
+    /// или перейти на keyset-пагинацию.
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use sieve::SieveModel;
+    /// let model = SieveModel::new(&Some(100000), &Some(50), &None, &None);
+    /// assert_eq!(model.estimated_row_limit(), 5_000_000);
+    /// 
+    /// let model = SieveModel::new(&Some(u64::MAX), &Some(u64::MAX), &None, &None);
+    /// assert_eq!(model.estimated_row_limit(), u64::MAX);
+    /// ```
+    pub fn estimated_row_limit(&self) -> u64 {
+        self.offset().saturating_add(self.page_size)
+    }
+
+    /// Возвращает размер страницы, урезанный так, чтобы `offset + limit` не превышал `max_rows`
+    /// 
+    /// Страница, которая целиком лежит за пределом, получает лимит `0`.
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use sieve::SieveModel;
+    /// // страница целиком в пределах лимита
+    /// let model = SieveModel::new(&Some(2), &Some(20), &None, &None);
+    /// assert_eq!(model.apply_row_limit(100), 20);
+    /// 
+    /// // страница частично выходит за лимит: 80 + 15 = 95
+    /// let model = SieveModel::new(&Some(5), &Some(20), &None, &None);
+    /// assert_eq!(model.apply_row_limit(95), 15);
+    /// 
+    /// // страница целиком за пределом лимита
+    /// let model = SieveModel::new(&Some(10), &Some(20), &None, &None);
+    /// assert_eq!(model.apply_row_limit(100), 0);
+    /// ```
+    pub fn apply_row_limit(&self, max_rows: u64) -> u64 {
+        self.page_size.min(max_rows.saturating_sub(self.offset()))
+    }
+
+    /// Возвращает поля, по которым фильтруют с указанным оператором, в порядке
+    /// первого упоминания и без повторов
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use sieve::{FilterOperator, SieveModel};
+    /// let model = SieveModel::new(
+    ///     &None,
+    ///     &None,
+    ///     &Some("(title|artist)@=Queen,year>1970,album@=*live,title!@=demo,artist==Queen".to_string()),
+    ///     &None
+    /// );
+    /// assert_eq!(model.fields_with_operator(&FilterOperator::Contains), vec!["title", "artist", "album"]);
+    /// assert_eq!(model.fields_with_operator(&FilterOperator::Equals), vec!["artist"]);
+    /// assert_eq!(model.fields_with_operator(&FilterOperator::GreaterThan), vec!["year"]);
+    /// assert!(model.fields_with_operator(&FilterOperator::StartsWith).is_empty());
+    /// ```
+    pub fn fields_with_operator(&self, operator: &FilterOperator) -> Vec<String> {
+        let mut result: Vec<String> = Vec::new();
+        for term in self.filters.iter().flatten().filter(|term| term.operator() == *operator) {
+            for name in term.names() {
+                if !result.contains(&name) {
+                    result.push(name);
+                }
+            }
+        }
+        result
+    }
+
+    /// Возвращает копию модели без фильтров и сортировок по полям, которых нет в схеме
+    /// 
+    /// Из термов с несколькими полями удаляются только неизвестные поля;
+    /// терм отбрасывается, если в нем не осталось ни одного поля.
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use sieve::{FieldType, SieveModel, SieveSchema};
+    /// let schema = SieveSchema::new()
+    ///     .field("title", FieldType::Text)
+    ///     .field("year", FieldType::Number);
+    /// let model = SieveModel::new(
+    ///     &None,
+    ///     &None,
+    ///     &Some("password==x,(title|secret)@=Rock,(a|b)==1,year>2000".to_string()),
+    ///     &Some("-secret,year".to_string())
+    /// );
+    /// 
+    /// let sanitized = model.without_unknown_fields(&schema);
+    /// let filters = sanitized.filters().unwrap();
+    /// assert_eq!(filters.len(), 2);
+    /// assert_eq!(filters[0].names(), vec!["title"]);
+    /// assert_eq!(filters[1].names(), vec!["year"]);
+    /// let sorts = sanitized.sorts().unwrap();
+    /// assert_eq!(sorts.len(), 1);
+    /// assert_eq!(sorts[0].name(), "year");
+    /// 
+    /// // исходная модель не меняется
+    /// assert_eq!(model.filters().unwrap().len(), 4);
+    /// ```
+    pub fn without_unknown_fields(&self, schema: &SieveSchema) -> SieveModel {
+        let mut model = self.clone();
+        model.filters = self.filters.as_ref().map(|filters| {
+            filters
+                .iter()
+                .filter_map(|term| {
+                    let names: Vec<String> = term.names().into_iter().filter(|name| schema.contains(name)).collect();
+                    (!names.is_empty()).then(|| term.with_names(names))
+                })
+                .collect()
+        });
+        model.sorts = self.sorts.as_ref().map(|sorts| {
+            sorts.iter().filter(|sort| schema.contains(&sort.name())).cloned().collect()
+        });
+        model
+    }
+
+    /// Возвращает детерминированный строковый ключ модели для кэширования
+    /// 
+    /// Ключ не зависит от порядка фильтров, полей и значений внутри терма,
+    /// так как они объединяются коммутативно. Порядок сортировки сохраняется.
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use sieve::SieveModel;
+    /// let a = SieveModel::new(&None, &Some(10), &Some("title@=Rock|Pop, year>2000".to_string()), &Some("-year".to_string()));
+    /// let b = SieveModel::new(&Some(1), &Some(10), &Some("year>2000,title@=Pop|Rock".to_string()), &Some("-year".to_string()));
+    /// assert_eq!(a.canonical_key(), b.canonical_key());
+    /// assert_eq!(a.canonical_key(), "page=1;page_size=10;filters=title@=Pop|Rock,year>2000;sorts=-year");
+    /// 
+    /// let c = SieveModel::new(&Some(2), &Some(10), &Some("year>2000,title@=Pop|Rock".to_string()), &Some("-year".to_string()));
+    /// assert_ne!(a.canonical_key(), c.canonical_key());
+    /// 
+    /// // модификатор и его экранированный символ дают разные ключи
+    /// let key = |filters: &str| SieveModel::new(&None, &None, &Some(filters.to_string()), &None).canonical_key();
+    /// for (a, b) in [
+    ///     ("title==*Rock", "title==\\*Rock"),
+    ///     ("year==2000:int", "year==2000\\:int"),
+    ///     ("title!@=Rock", "title\\!@=Rock"),
+    ///     ("status==$null", "status==\\$null"),
+    ///     ("a>=5", "a>\\=5"),
+    ///     ("a_==b", "a==_b"),
+    ///     ("(a|b)==x", "a\\|b==x"),
+    /// ] {
+    ///     assert_ne!(key(a), key(b), "{} {}", a, b);
+    /// }
+    /// 
+    /// // мягко удаленные записи меняют результат запроса
+    /// let with_deleted = a.clone().with_include_deleted(&Some("true".to_string()));
+    /// assert_ne!(a.canonical_key(), with_deleted.canonical_key());
+    /// assert!(with_deleted.canonical_key().ends_with(";include_deleted"));
+    /// ```
+    pub fn canonical_key(&self) -> String {
+        let mut filters: Vec<String> = self.filters.iter().flatten().map(SieveModel::canonical_term).collect();
+        filters.sort();
+
+        let sorts: Vec<String> = self.sorts().iter().flatten().map(|sort| {
+            let modifier = if sort.case_insensitive() { ":ci" } else { "" };
+            match sort.order() {
+                SortOrder::Ascending => format!("{}{}", canonical_name(&sort.name()), modifier),
+                SortOrder::Descending => format!("-{}{}", canonical_name(&sort.name()), modifier),
+            }
+        }).collect();
+
+        let mut key = format!("page={};page_size={};filters={};sorts={}", self.page(), self.page_size, filters.join(","), sorts.join(","));
+        if self.distinct {
+            key.push_str(";distinct");
+        }
+        if self.zero_based_pages {
+            key.push_str(";zero_based_pages");
+        }
+        if self.include_deleted {
+            key.push_str(";include_deleted");
+        }
+        if let Some(locale) = &self.locale {
+            key.push_str(&format!(";locale={}", locale));
+        }
+        key
+    }
+
+    /// Возвращает детерминированное строковое представление терма фильтрации
+    /// 
+    /// Поля и значения сортируются, поэтому равнозначные термы дают одну строку.
+    pub(crate) fn canonical_term(term: &FilterTerm) -> String {
+        let mut names: Vec<String> = term.names().iter().map(|name| canonical_name(name)).collect();
+        let mut values: Vec<String> = term
+            .values()
+            .iter()
+            .map(|value| if term.is_null_value(value) { value.clone() } else { canonical_value(value) })
+            .collect();
+        names.sort();
+        values.sort();
+        let names = if names.len() == 1 { names.join("|") } else { format!("({})", names.join("|")) };
+        let negation = if term.negated() { "!" } else { "" };
+        let modifier = if term.case_insensitive() { "*" } else { "" };
+        let value_type = term.value_type().map(|value_type| format!(":{}", value_type)).unwrap_or_default();
+        format!("{}{}{}{}{}{}", names, negation, term.operator(), modifier, values.join("|"), value_type)
+    }
+
+    /// Возвращает параметры страницы для указанного общего количества записей
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use sieve::SieveModel;
+    /// let model = SieveModel::new(&Some(2), &Some(10), &None, &None);
+    /// assert_eq!(model.paging(25).total_pages(), 3);
+    /// ```
+    pub fn paging(&self, total: u64) -> Paging {
+        Paging::new(self.page(), self.page_size, self.offset(), total)
+    }
+
     /// Возвращает условие фильтрации
     /// 
     /// # Примеры
@@ -186,60 +780,289 @@ impl SieveModel {
     /// # Примеры
     /// - `Some(vec![SortTerm::from_str("title")])` - сортировка по названию
     /// - `Some(vec![SortTerm::from_str("-created_time")])` - сортировка по дате создания по убыванию
+    /// - `None` - сортировка не задана или передана пустая строка
     pub fn sorts(&self) -> Option<Vec<SortTerm>> {
-        self.sorts.clone()
+        let sorts = self.sorts.clone()?;
+        match sorts.first() {
+            Some(first) if self.inherit_sort_order && first.order() == SortOrder::Descending => Some(
+                sorts
+                    .iter()
+                    .map(|sort| if sort.explicit_order() { sort.clone() } else { sort.with_order(SortOrder::Descending) })
+                    .collect(),
+            ),
+            _ => Some(sorts),
+        }
+    }
+
+    /// Разбирает логический флаг из значения параметра запроса
+    fn parse_flag(flag: &Option<String>) -> bool {
+        flag.as_ref()
+            .map(|flag| matches!(flag.trim().to_lowercase().as_str(), "true" | "1" | "yes" | "on"))
+            .unwrap_or(false)
     }
 
     /// Разбирает строку фильтров на отдельные термы
     /// 
+    /// Пустая строка или строка из одних пробелов (`filters=`) равнозначна
+    /// отсутствию параметра и дает `None`.
+    /// 
     /// # Примеры
     /// ```
+    /// # use sieve::SieveModel;
     /// let model = SieveModel::new(
-    ///     Some(1),
-    ///     Some(10),
-    ///     Some("title@=Rock,artist==Queen".to_string()),
-    ///     None
+    ///     &Some(1),
+    ///     &Some(10),
+    ///     &Some("title@=Rock,artist==Queen".to_string()),
+    ///     &None
     /// );
+    /// assert_eq!(model.filters().unwrap()[1].names(), vec!["artist"]);
+    /// 
+    /// // цепочка сравнений дает два терма, некорректная цепочка отбрасывается
+    /// let model = SieveModel::new(&None, &None, &Some("18<=age<=65,10<x>5".to_string()), &None);
+    /// let filters = model.filters().unwrap();
+    /// assert_eq!(filters.len(), 2);
+    /// assert_eq!(filters[0].values(), vec!["18"]);
+    /// assert_eq!(filters[1].values(), vec!["65"]);
+    /// 
+    /// let model = SieveModel::new(&None, &None, &Some("  ".to_string()), &None);
+    /// assert!(model.filters().is_none());
     /// ```
     fn parse_filters(filters: &Option<String>) -> Option<Vec<FilterTerm>> {
-        filters.as_ref().map(|filters| {
+        SieveModel::parse_filters_verbose(filters, &mut Vec::new())
+    }
+
+    /// Разбирает строку фильтров, собирая ошибки некорректных термов в `errors`
+    fn parse_filters_verbose(filters: &Option<String>, errors: &mut Vec<SieveParseError>) -> Option<Vec<FilterTerm>> {
+        filters.as_ref().filter(|filters| !filters.trim().is_empty()).map(|filters| {
             let mut result = Vec::new();
             
-            // Разбиваем по запятой, учитывая экранирование
-            for filter in filters.split(',') {
-                let term = FilterTerm::from_str(filter);
-                if let Ok(term) = term {
-                    result.push(term);
+            // Разбиваем по запятой, учитывая экранирование и списки в скобках
+            for (offset, filter) in SieveModel::split_filters(filters) {
+                let term = if FilterTerm::is_chain(filter) {
+                    FilterTerm::from_chain(filter)
+                } else {
+                    FilterTerm::from_str(filter).map(|term| vec![term])
+                };
+                match term {
+                    Ok(terms) => result.extend(terms),
+                    Err(message) => errors.push(SieveParseError::new("filters", message, offset, filter)),
                 }
             }
             result
         })
     }
 
+    /// Разбивает строку фильтров по запятым верхнего уровня
+    /// 
+    /// Запятые внутри квадратных скобок (`status==[active,pending]`)
+    /// и экранированные запятые (`title@=Rock\, Metal`) не разделяют термы.
+    /// Экранирование сохраняется для разбора отдельного терма.
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use sieve::SieveModel;
+    /// let model = SieveModel::new(&None, &None, &Some("status==[active,pending],year>2000".to_string()), &None);
+    /// let filters = model.filters().unwrap();
+    /// assert_eq!(filters.len(), 2);
+    /// assert_eq!(filters[0].values(), vec!["active", "pending"]);
+    /// assert_eq!(filters[1].names(), vec!["year"]);
+    /// 
+    /// let model = SieveModel::new(&None, &None, &Some("title@=Rock\\, Metal,year>2000".to_string()), &None);
+    /// let filters = model.filters().unwrap();
+    /// assert_eq!(filters.len(), 2);
+    /// assert_eq!(filters[0].values(), vec!["Rock, Metal"]);
+    /// 
+    /// // пробелы между термами и вокруг значения обрезаются,
+    /// // а пробелы в кавычках или экранированные - сохраняются
+    /// let model = SieveModel::new(&None, &None, &Some("a==x, b== y, c==\" y\", d==\\ y,e==[\" p \", q ]".to_string()), &None);
+    /// let values: Vec<Vec<String>> = model.filters().unwrap().iter().map(|term| term.values()).collect();
+    /// assert_eq!(values, vec![vec!["x"], vec!["y"], vec![" y"], vec![" y"], vec![" p ", "q"]]);
+    /// 
+    /// // скобка не после оператора - обычный символ, незакрытый список - ошибка терма
+    /// let model = SieveModel::new(&None, &None, &Some("title@=a[b,year>2000".to_string()), &None);
+    /// let values: Vec<Vec<String>> = model.filters().unwrap().iter().map(|term| term.values()).collect();
+    /// assert_eq!(values, vec![vec!["a[b"], vec!["2000"]]);
+    /// 
+    /// let (model, errors) = SieveModel::parse_verbose(&None, &None, &Some("title@=[draft,year>2000".to_string()), &None);
+    /// assert_eq!(model.filters().unwrap().len(), 1);
+    /// assert_eq!(model.filters().unwrap()[0].names(), vec!["year"]);
+    /// assert_eq!(errors.len(), 1);
+    /// assert_eq!(errors[0].span(), 0..13);
+    /// assert_eq!(errors[0].message(), "Unbalanced bracket in value [draft");
+    /// 
+    /// // вложенные скобки в списке
+    /// let (model, errors) = SieveModel::parse_verbose(&None, &None, &Some("tag==[a[1],b],year>2000".to_string()), &None);
+    /// assert!(errors.is_empty());
+    /// let values: Vec<Vec<String>> = model.filters().unwrap().iter().map(|term| term.values()).collect();
+    /// assert_eq!(values, vec![vec!["a[1]", "b"], vec!["2000"]]);
+    /// 
+    /// let (model, errors) = SieveModel::parse_verbose(&None, &None, &Some("tag==[[a,b],c]".to_string()), &None);
+    /// assert!(errors.is_empty());
+    /// assert_eq!(model.filters().unwrap()[0].values(), vec!["[a,b]", "c"]);
+    /// 
+    /// // `]` без пары - ошибка терма
+    /// let (model, errors) = SieveModel::parse_verbose(&None, &None, &Some("tag==a],tag==[a]]],year>2000".to_string()), &None);
+    /// assert_eq!(model.filters().unwrap().len(), 1);
+    /// let messages: Vec<String> = errors.iter().map(|error| error.message()).collect();
+    /// assert_eq!(messages, vec!["Unbalanced bracket in value a]", "Unbalanced bracket in value [a]]]"]);
+    /// ```
+    fn split_filters(filters: &str) -> Vec<(usize, &str)> {
+        split_raw_indexed(filters, ',', true)
+    }
+
     /// Парсит строку сортировки и возвращает вектор SortTerm
     /// 
     /// # Формат
     /// `поле1,-поле2`
     /// 
+    /// Пустая строка или строка из одних пробелов (`sorts=`) равнозначна
+    /// отсутствию параметра и дает `None`.
+    /// 
+    /// Термы с приоритетом (`title:2,artist:1`) упорядочиваются по нему,
+    /// термы с одинаковым приоритетом сохраняют исходный порядок, а термы
+    /// без приоритета идут после них в исходном порядке.
+    /// 
     /// # Примеры
     /// ```
-    /// let model = SieveModel {
-    ///     page: Some(1),
-    ///     page_size: Some(10),
-    ///     filters: None,
-    ///     sorts: Some("title,-created_time".to_string()),
-    /// };
+    /// # use sieve::SieveModel;
+    /// let model = SieveModel::new(
+    ///     &Some(1),
+    ///     &Some(10),
+    ///     &None,
+    ///     &Some("title,-created_time".to_string())
+    /// );
+    /// assert_eq!(model.sorts().unwrap()[1].name(), "created_time");
+    /// 
+    /// let model = SieveModel::new(&None, &None, &None, &Some("".to_string()));
+    /// assert!(model.sorts().is_none());
+    /// let model = SieveModel::new(&None, &None, &None, &Some("  ".to_string()));
+    /// assert!(model.sorts().is_none());
+    /// let model = SieveModel::new(&None, &None, &None, &None);
+    /// assert!(model.sorts().is_none());
+    /// 
+    /// let names = |sorts: &str| SieveModel::new(&None, &None, &None, &Some(sorts.to_string()))
+    ///     .sorts()
+    ///     .unwrap()
+    ///     .iter()
+    ///     .map(|sort| sort.name())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(names("title:2,artist:1"), vec!["artist", "title"]);
+    /// assert_eq!(names("year,title:2,artist:1"), vec!["artist", "title", "year"]);
+    /// assert_eq!(names("title:1,artist:1,-year:0"), vec!["year", "title", "artist"]);
     /// ```
     fn parse_sorts(sorts: &Option<String>) -> Option<Vec<SortTerm>> {
-        sorts.as_ref().map(|sorts| {
+        SieveModel::parse_sorts_verbose(sorts, &mut Vec::new())
+    }
+
+    /// Разбирает строку сортировки, собирая ошибки некорректных термов в `errors`
+    fn parse_sorts_verbose(sorts: &Option<String>, errors: &mut Vec<SieveParseError>) -> Option<Vec<SortTerm>> {
+        sorts.as_ref().filter(|sorts| !sorts.trim().is_empty()).map(|sorts| {
             let mut result = Vec::new();
-            for sort in COMMA_PATTERN.split(sorts) {
-                let term = SortTerm::from_str(sort);
-                if let Ok(term) = term {
-                    result.push(term);
+            let mut offset = 0;
+            let separators = COMMA_PATTERN.find_iter(sorts).map(|separator| (separator.start(), separator.end()));
+            for (end, next) in separators.chain(std::iter::once((sorts.len(), sorts.len()))) {
+                let sort = &sorts[offset..end];
+                match SortTerm::from_str(sort) {
+                    Ok(term) => result.push(term),
+                    Err(message) => errors.push(SieveParseError::new("sorts", message, offset, sort)),
                 }
+                offset = next;
             }
+            result.sort_by_key(|term| (term.priority().is_none(), term.priority()));
             result
         })
     }
 }
+
+/// Экранирует имя поля для канонического ключа
+/// 
+/// Экранируются разделители и все символы операторов и модификаторов,
+/// а также `_` и `-` в конце имени (начало `_=` и `_-=`) и `-` в начале
+/// (сортировка по убыванию), чтобы граница имени и оператора была однозначной.
+fn canonical_name(name: &str) -> String {
+    let last = name.chars().count().saturating_sub(1);
+    let mut result = String::with_capacity(name.len());
+    for (index, c) in name.chars().enumerate() {
+        if "\\,|;()[]*:!=<>@$\"'".contains(c) || (index == last && matches!(c, '_' | '-')) || (index == 0 && c == '-') {
+            result.push('\\');
+        }
+        result.push(c);
+    }
+    result
+}
+
+/// Экранирует значение фильтра для канонического ключа
+/// 
+/// Кроме символов [`escape_value`] экранируются `;` и `=` в начале значения
+/// (`>` со значением `=5` и `>=` со значением `5` - разные термы).
+fn canonical_value(value: &str) -> String {
+    let value = escape_value(value).replace(';', "\\;");
+    match value.strip_prefix('=') {
+        Some(rest) => format!("\\={}", rest),
+        None => value,
+    }
+}
+
+/// Параметры запроса в исходном виде для десериализации SieveModel
+/// 
+/// Принимает `page`, `pageSize` (или `page_size`), `filters` и `sorts`;
+/// строки фильтрации и сортировки разбираются так же, как в [`SieveModel::new`].
+/// Флаги `distinct` и `includeDeleted` (или `include_deleted`) и `locale`
+/// передаются строками и обрабатываются так же, как в `with_distinct`,
+/// `with_include_deleted` и `with_locale`.
+/// 
+/// Реализация доступна с функцией `serde`, которая подключает serde derive.
+/// Без нее крейт использует только `serde` без derive для сериализации
+/// [`PagedList`](crate::PagedList).
+/// 
+/// # Примеры
+/// ```
+/// # use sieve::SieveModel;
+/// let model: SieveModel = serde_json::from_str(
+///     r#"{"page": 2, "pageSize": 20, "filters": "title@=Rock,year>2000", "sorts": "-year"}"#
+/// ).unwrap();
+/// assert_eq!(model.page(), 2);
+/// assert_eq!(model.page_size(), 20);
+/// assert_eq!(model.filters().unwrap().len(), 2);
+/// assert_eq!(model.sorts().unwrap()[0].name(), "year");
+/// 
+/// let model: SieveModel = serde_json::from_str(r#"{"page_size": 5, "filters": " "}"#).unwrap();
+/// assert_eq!(model.page(), 1);
+/// assert_eq!(model.page_size(), 5);
+/// assert!(model.filters().is_none());
+/// 
+/// let model: SieveModel = serde_json::from_str("{}").unwrap();
+/// assert_eq!(model.page_size(), 100);
+/// assert!(model.sorts().is_none());
+/// assert!(!model.distinct());
+/// 
+/// let model: SieveModel = serde_json::from_str(
+///     r#"{"distinct": "true", "includeDeleted": "1", "locale": "sv-SE"}"#
+/// ).unwrap();
+/// assert!(model.distinct());
+/// assert!(model.include_deleted());
+/// assert_eq!(model.locale(), Some("sv-SE".to_string()));
+/// ```
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct SieveQuery {
+    page: Option<u64>,
+    #[serde(alias = "pageSize")]
+    page_size: Option<u64>,
+    filters: Option<String>,
+    sorts: Option<String>,
+    distinct: Option<String>,
+    #[serde(alias = "includeDeleted")]
+    include_deleted: Option<String>,
+    locale: Option<String>,
+}
+
+#[cfg(feature = "serde")]
+impl From<SieveQuery> for SieveModel {
+    fn from(query: SieveQuery) -> Self {
+        SieveModel::new(&query.page, &query.page_size, &query.filters, &query.sorts)
+            .with_distinct(&query.distinct)
+            .with_include_deleted(&query.include_deleted)
+            .with_locale(&query.locale)
+    }
+}
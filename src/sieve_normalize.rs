@@ -0,0 +1,119 @@
+use std::collections::HashSet;
+use super::diagnostic::Diagnostic;
+use super::filter_term::FilterTerm;
+use super::normalize_options::NormalizeOptions;
+use super::sieve_model::SieveModel;
+use super::sieve_schema::SieveSchema;
+use super::sort_order::SortOrder;
+use super::sort_term::SortTerm;
+
+/// Нормализация SieveModel по схеме
+impl SieveModel {
+    /// Проверяет модель по схеме и приводит ее к виду для применения на сервере
+    /// 
+    /// Шаги выполняются по порядку:
+    /// 1. поля переименовываются по `NormalizeOptions::with_field`;
+    /// 2. при `with_drop_unknown_fields` удаляются термы с неизвестными полями;
+    /// 3. модель проверяется по схеме (`validate_sql`), ошибки возвращаются все сразу;
+    /// 4. удаляются термы фильтрации, совпадающие после разбора целиком
+    ///    (`year>2000,year<2010` и `title==*Rock,title==\*Rock` остаются оба)
+    ///    и повторные сортировки по одному полю (остается первая);
+    /// 5. в конец сортировки добавляется поле однозначной сортировки, если его там нет;
+    /// 6. размер страницы ограничивается `with_max_page_size`.
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use sieve::{Diagnostic, FieldType, NormalizeOptions, SchemaError, SieveModel, SieveSchema, SortOrder};
+    /// let schema = SieveSchema::new()
+    ///     .field("id", FieldType::Number)
+    ///     .field("title", FieldType::Text)
+    ///     .field("year", FieldType::Number);
+    /// let options = NormalizeOptions::new()
+    ///     .with_field("name", "title")
+    ///     .with_max_page_size(50)
+    ///     .with_tiebreaker("id")
+    ///     .with_drop_unknown_fields(true);
+    /// 
+    /// let model = SieveModel::new(
+    ///     &Some(2),
+    ///     &Some(500),
+    ///     &Some("name@=Rock,year>2000,title@=Rock,year<2010,secret==x".to_string()),
+    ///     &Some("-year,name,year,secret".to_string())
+    /// );
+    /// let normalized = model.validate_and_normalize(&schema, &options).unwrap();
+    /// assert_eq!(normalized.page(), 2);
+    /// assert_eq!(normalized.page_size(), 50);
+    /// 
+    /// let filters = normalized.filters().unwrap();
+    /// assert_eq!(filters.len(), 3);
+    /// assert_eq!(filters[0].names(), vec!["title"]);
+    /// assert_eq!(filters[1].values(), vec!["2000"]);
+    /// assert_eq!(filters[2].values(), vec!["2010"]);
+    /// 
+    /// let sorts: Vec<(String, bool)> = normalized.sorts().unwrap().iter()
+    ///     .map(|sort| (sort.name(), sort.order() == SortOrder::Descending))
+    ///     .collect();
+    /// assert_eq!(sorts, vec![
+    ///     ("year".to_string(), true),
+    ///     ("title".to_string(), false),
+    ///     ("id".to_string(), false),
+    /// ]);
+    /// 
+    /// // экранированный модификатор - часть значения, а не другая запись того же терма
+    /// let starred = SieveModel::new(&None, &None, &Some(r"title==*Rock,title==\*Rock,title==*Rock".to_string()), &None);
+    /// let filters = starred.validate_and_normalize(&schema, &NormalizeOptions::new()).unwrap().filters().unwrap();
+    /// assert_eq!(filters.len(), 2);
+    /// assert_eq!(filters[0].values(), vec!["Rock"]);
+    /// assert!(filters[0].case_insensitive());
+    /// assert_eq!(filters[1].values(), vec!["*Rock"]);
+    /// assert!(!filters[1].case_insensitive());
+    /// 
+    /// // без удаления неизвестных полей возвращаются все замечания
+    /// let options = options.with_drop_unknown_fields(false).with_tiebreaker("uuid");
+    /// assert_eq!(model.validate_and_normalize(&schema, &options).unwrap_err(), vec![
+    ///     Diagnostic::Schema(SchemaError::UnknownField("secret".to_string())),
+    ///     Diagnostic::Schema(SchemaError::UnknownField("secret".to_string())),
+    ///     Diagnostic::UnknownTiebreaker("uuid".to_string()),
+    /// ]);
+    /// ```
+    pub fn validate_and_normalize(&self, schema: &SieveSchema, options: &NormalizeOptions) -> Result<SieveModel, Vec<Diagnostic>> {
+        let filters = self.filters().unwrap_or_default().iter().map(|term| {
+            let mut seen = HashSet::new();
+            let names = term.names().iter().map(|name| options.field(name)).filter(|name| seen.insert(name.clone())).collect();
+            term.with_names(names)
+        }).collect();
+        let sorts = self.sorts().unwrap_or_default().iter().map(|sort| sort.with_name(&options.field(&sort.name()))).collect();
+        let mut model = self.with_terms(filters, sorts);
+        if options.drop_unknown_fields() {
+            model = model.without_unknown_fields(schema);
+        }
+
+        let mut diagnostics: Vec<Diagnostic> = model.validate_sql(schema).err().unwrap_or_default().into_iter().map(Diagnostic::from).collect();
+        let tiebreaker = options.tiebreaker();
+        if let Some(tiebreaker) = tiebreaker.as_ref().filter(|tiebreaker| !schema.contains(tiebreaker)) {
+            diagnostics.push(Diagnostic::UnknownTiebreaker(tiebreaker.clone()));
+        }
+        if !diagnostics.is_empty() {
+            return Err(diagnostics);
+        }
+
+        let mut filters: Vec<FilterTerm> = Vec::new();
+        for term in model.filters().unwrap_or_default() {
+            if !filters.contains(&term) {
+                filters.push(term);
+            }
+        }
+        let mut seen = HashSet::new();
+        let mut sorts: Vec<SortTerm> = model.sorts().unwrap_or_default().into_iter().filter(|sort| seen.insert(sort.name())).collect();
+        if let Some(tiebreaker) = tiebreaker.filter(|tiebreaker| !seen.contains(tiebreaker)) {
+            sorts.push(SortTerm::new(&tiebreaker, SortOrder::Ascending));
+        }
+
+        let mut model = model.with_terms(filters, sorts);
+        if let Some(max_page_size) = options.max_page_size() {
+            let page_size = model.page_size().clamp(1, max_page_size.max(1));
+            model = model.with_page_size(page_size);
+        }
+        Ok(model)
+    }
+}
@@ -0,0 +1,117 @@
+use std::cmp::Ordering;
+use super::field_value::FieldValue;
+use super::sieve_config::SieveConfig;
+use super::sieve_locale::fold_case;
+use super::sieve_model::SieveModel;
+use super::sieveable::Sieveable;
+use super::sort_order::SortOrder;
+
+/// Сортировка коллекции в памяти по термам сортировки SieveModel
+pub trait SieveOrder<T> {
+    /// Возвращает элементы, отсортированные по всем термам сортировки
+    /// 
+    /// Значения полей берутся из функции, заданной в [`SieveConfig::with_accessor`].
+    fn order_with_config(self, sieve: &SieveModel, config: &SieveConfig<T>) -> Vec<T>;
+
+    /// Возвращает элементы, отсортированные по всем термам сортировки
+    /// 
+    /// Значения полей берутся из [`Sieveable::field`].
+    fn order_with(self, sieve: &SieveModel) -> Vec<T>
+    where
+        T: Sieveable;
+}
+
+impl<T> SieveOrder<T> for Vec<T> {
+    fn order_with_config(self, sieve: &SieveModel, config: &SieveConfig<T>) -> Vec<T> {
+        sort_items(self, sieve, |item, name| config.field(item, name))
+    }
+
+    fn order_with(self, sieve: &SieveModel) -> Vec<T>
+    where
+        T: Sieveable,
+    {
+        sort_items(self, sieve, |item, name| item.field(name))
+    }
+}
+
+/// Сортирует элементы по термам сортировки SieveModel
+/// 
+/// Сортировка устойчивая: элементы с равными ключами сохраняют исходный порядок.
+/// Элементы без значения поля (или со значением `FieldValue::Null`)
+/// считаются меньше любого значения: при сортировке по возрастанию они идут
+/// первыми, а при сортировке по убыванию - последними, как в MySQL и SQLite
+/// (в Postgres по умолчанию наоборот).
+/// 
+/// # Примеры
+/// ```
+/// # use sieve::{sort_items, FieldValue, SieveModel};
+/// let sieve = SieveModel::new(&None, &None, &None, &Some("-value".to_string()));
+/// let items = sort_items(vec![2, 3, 1], &sieve, |item, name| {
+///     (name == "value").then(|| FieldValue::from(*item as i64))
+/// });
+/// assert_eq!(items, vec![3, 2, 1]);
+/// 
+/// // элементы без значения: первыми по возрастанию, последними по убыванию
+/// let sort = |sorts: &str| {
+///     let sieve = SieveModel::new(&None, &None, &None, &Some(sorts.to_string()));
+///     sort_items(vec![Some(2), None, Some(1)], &sieve, |item, _| Some(FieldValue::from(item.map(i64::from))))
+/// };
+/// assert_eq!(sort("value"), vec![None, Some(1), Some(2)]);
+/// assert_eq!(sort("-value"), vec![Some(2), Some(1), None]);
+/// 
+/// // `:ci` сравнивает строки без учета регистра
+/// let words = vec!["apple", "Banana", "cherry"];
+/// let sort = |sorts: &str| {
+///     let sieve = SieveModel::new(&None, &None, &None, &Some(sorts.to_string()));
+///     sort_items(words.clone(), &sieve, |word, _| Some(FieldValue::from(*word)))
+/// };
+/// assert_eq!(sort("word"), vec!["Banana", "apple", "cherry"]);
+/// assert_eq!(sort("word:ci"), vec!["apple", "Banana", "cherry"]);
+/// assert_eq!(sort("-word:ci"), vec!["cherry", "Banana", "apple"]);
+/// 
+/// // числа идут раньше строк, `NaN` и `Infinity` - строки
+/// let sieve = SieveModel::new(&None, &None, &None, &Some("value".to_string()));
+/// let values = sort_items(vec!["10a", "NaN", "9", "10", "Infinity", "1.5"], &sieve, |value, _| Some(FieldValue::from(*value)));
+/// assert_eq!(values, vec!["1.5", "9", "10", "10a", "Infinity", "NaN"]);
+/// ```
+pub fn sort_items<T, F>(mut items: Vec<T>, sieve: &SieveModel, field: F) -> Vec<T>
+where
+    F: Fn(&T, &str) -> Option<FieldValue>,
+{
+    let sorts = sieve.sorts().unwrap_or_default();
+    let locale = sieve.locale();
+    if sorts.is_empty() {
+        return items;
+    }
+
+    items.sort_by(|a, b| {
+        sorts.iter().fold(Ordering::Equal, |ordering, sort| {
+            ordering.then_with(|| {
+                let name = sort.name();
+                let ordering = compare_fields(field(a, &name), field(b, &name), sort.case_insensitive(), locale.as_deref());
+                match sort.order() {
+                    SortOrder::Ascending => ordering,
+                    SortOrder::Descending => ordering.reverse(),
+                }
+            })
+        })
+    });
+    items
+}
+
+/// Сравнивает значения поля двух элементов
+/// 
+/// При `case_insensitive` строки сравниваются в нижнем регистре.
+fn compare_fields(a: Option<FieldValue>, b: Option<FieldValue>, case_insensitive: bool, locale: Option<&str>) -> Ordering {
+    let fold = |value: FieldValue| match value {
+        FieldValue::String(value) if case_insensitive => FieldValue::String(fold_case(&value, locale)),
+        value => value,
+    };
+    match (a, b) {
+        (Some(a), Some(b)) if !a.is_null() && !b.is_null() => fold(a).collate(&fold(b), locale),
+        (a, b) => {
+            let present = |value: &Option<FieldValue>| value.as_ref().is_some_and(|value| !value.is_null());
+            present(&a).cmp(&present(&b))
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use super::paged_list::PagedList;
+use super::paging::Paging;
+use super::sieve_model::SieveModel;
+
+/// Пагинация коллекции в памяти по параметрам SieveModel
+pub trait SievePaginate<T> {
+    /// Возвращает страницу, заданную `page` и `page_size`
+    fn paginate_with(self, sieve: &SieveModel) -> PagedList<T>;
+}
+
+impl<T> SievePaginate<T> for Vec<T> {
+    fn paginate_with(self, sieve: &SieveModel) -> PagedList<T> {
+        let (items, paging, total) = paginate_parts(self, sieve);
+        PagedList::new(items, total).with_offset(paging.offset())
+    }
+}
+
+/// Возвращает элементы страницы, параметры страницы и общее количество элементов
+/// 
+/// # Примеры
+/// ```
+/// # use sieve::{paginate_parts, SieveModel};
+/// let sieve = SieveModel::new(&Some(2), &Some(2), &None, &None);
+/// let (items, paging, total) = paginate_parts(vec![1, 2, 3, 4, 5], &sieve);
+/// assert_eq!(items, vec![3, 4]);
+/// assert_eq!(paging.page(), 2);
+/// assert_eq!(paging.offset(), 2);
+/// assert_eq!(paging.total_pages(), 3);
+/// assert_eq!(total, 5);
+/// ```
+pub fn paginate_parts<T>(items: Vec<T>, sieve: &SieveModel) -> (Vec<T>, Paging, u64) {
+    let total = items.len() as u64;
+    let paging = sieve.paging(total);
+    let offset = usize::try_from(paging.offset()).unwrap_or(usize::MAX);
+    let page_size = usize::try_from(paging.page_size()).unwrap_or(usize::MAX);
+    let items = items.into_iter().skip(offset).take(page_size).collect();
+    (items, paging, total)
+}
+
+/// Возвращает страницу из ссылок на элементы, не клонируя их
+/// 
+/// # Примеры
+/// ```
+/// # use sieve::{paginate_ref, SieveModel};
+/// let titles = vec!["One".to_string(), "Two".to_string(), "Three".to_string()];
+/// let sieve = SieveModel::new(&Some(2), &Some(2), &None, &None);
+/// let page = paginate_ref(&titles, &sieve);
+/// assert_eq!(page.items(), &vec![&titles[2]]);
+/// assert_eq!(page.total(), 3);
+/// assert_eq!(page.slice_stats(), (3, 3, 3));
+/// ```
+pub fn paginate_ref<'a, T>(items: &'a [T], sieve: &SieveModel) -> PagedList<&'a T> {
+    let total = items.len() as u64;
+    let paging = sieve.paging(total);
+    let start = usize::try_from(paging.offset()).unwrap_or(usize::MAX).min(items.len());
+    let page_size = usize::try_from(paging.page_size()).unwrap_or(usize::MAX);
+    let end = start.saturating_add(page_size).min(items.len());
+    PagedList::new(items[start..end].iter().collect(), total).with_offset(paging.offset())
+}
@@ -0,0 +1,54 @@
+use std::fmt;
+use std::ops::Range;
+
+/// Ошибка разбора параметра запроса с позицией в исходной строке
+/// 
+/// `span` - диапазон байтов ошибочного сегмента в строке параметра
+/// `parameter`, например для подсветки в редакторе запросов.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SieveParseError {
+    /// Имя параметра запроса (`filters` или `sorts`)
+    parameter: String,
+
+    /// Описание ошибки
+    message: String,
+
+    /// Диапазон байтов ошибочного сегмента
+    span: Range<usize>,
+}
+
+/// Реализация методов для SieveParseError
+impl SieveParseError {
+    /// Создает ошибку для сегмента `segment`, начинающегося со смещения `offset`
+    /// в строке параметра
+    /// 
+    /// Пробелы по краям сегмента в диапазон не входят.
+    pub(crate) fn new(parameter: &str, message: String, offset: usize, segment: &str) -> Self {
+        let start = offset + (segment.len() - segment.trim_start().len());
+        let end = start + segment.trim().len();
+        Self { parameter: parameter.to_string(), message, span: start..end }
+    }
+
+    /// Возвращает имя параметра запроса
+    pub fn parameter(&self) -> String {
+        self.parameter.clone()
+    }
+
+    /// Возвращает описание ошибки
+    pub fn message(&self) -> String {
+        self.message.clone()
+    }
+
+    /// Возвращает диапазон байтов ошибочного сегмента
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+}
+
+impl fmt::Display for SieveParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} [{}..{}]: {}", self.parameter, self.span.start, self.span.end, self.message)
+    }
+}
+
+impl std::error::Error for SieveParseError {}
@@ -4,6 +4,18 @@ use regex::Regex;
 lazy_static::lazy_static! {
     /// Регулярное выражение для разделения фильтров по запятой
     pub static ref COMMA_PATTERN: Regex = Regex::new(r",\s*").unwrap();
+
+    /// Регулярное выражение для определения цепочки сравнений (`18<=age<=65`)
+    pub static ref CHAIN_PREFIX_PATTERN: Regex = Regex::new(r"^\s*-?\d+(\.\d+)?\s*[<>]").unwrap();
+
+    /// Регулярное выражение для разбора цепочки сравнений `число оператор поле оператор число`
+    pub static ref CHAIN_PATTERN: Regex = Regex::new(r"^\s*(-?\d+(?:\.\d+)?)\s*(<=|<|>=|>)\s*([A-Za-z_][A-Za-z0-9_.]*)\s*(<=|<|>=|>)\s*(-?\d+(?:\.\d+)?)\s*$").unwrap();
+
+    /// Регулярное выражение для проверки даты или даты и времени в формате ISO 8601
+    pub static ref DATE_PATTERN: Regex = Regex::new(r"^\d{4}-\d{2}-\d{2}([T ]\d{2}:\d{2}(:\d{2}(\.\d+)?)?(Z|[+-]\d{2}:?\d{2})?)?$").unwrap();
+
+    /// Регулярное выражение для проверки имени поля перед подстановкой в SQL
+    pub static ref IDENTIFIER_PATTERN: Regex = Regex::new(r"^[A-Za-z_][A-Za-z0-9_]*(\.[A-Za-z_][A-Za-z0-9_]*)?$").unwrap();
     
     // /// Регулярное выражение для определения операторов фильтрации
     // pub static ref OPERATORS_REGEX: Regex = Regex::new(r"(!@=\*|!_=\*|!_-=\*|!=\*|!@=|!_=|!_-=|==\*|@=\*|_=\*|_-=\*|==|!=|>=|<=|>|<|@=|_=|_-=)").unwrap();
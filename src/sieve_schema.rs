@@ -0,0 +1,50 @@
+use super::field_type::FieldType;
+
+/// Схема допустимых полей и их типов
+/// 
+/// # Примеры
+/// ```
+/// # use sieve::{FieldType, SieveSchema};
+/// let schema = SieveSchema::new()
+///     .field("title", FieldType::Text)
+///     .field("year", FieldType::Number);
+/// assert_eq!(schema.field_type("year"), Some(FieldType::Number));
+/// assert!(!schema.contains("password"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SieveSchema {
+    /// Поля схемы в порядке добавления
+    fields: Vec<(String, FieldType)>,
+}
+
+/// Реализация методов для SieveSchema
+impl SieveSchema {
+    /// Создает пустую схему
+    pub fn new() -> Self {
+        Self { fields: Vec::new() }
+    }
+
+    /// Добавляет поле в схему (повторное добавление заменяет тип)
+    pub fn field(mut self, name: &str, field_type: FieldType) -> Self {
+        match self.fields.iter_mut().find(|(field, _)| field == name) {
+            Some(field) => field.1 = field_type,
+            None => self.fields.push((name.to_string(), field_type)),
+        }
+        self
+    }
+
+    /// Возвращает тип поля или `None`, если поля нет в схеме
+    pub fn field_type(&self, name: &str) -> Option<FieldType> {
+        self.fields.iter().find(|(field, _)| field == name).map(|(_, field_type)| *field_type)
+    }
+
+    /// Проверяет, есть ли поле в схеме
+    pub fn contains(&self, name: &str) -> bool {
+        self.field_type(name).is_some()
+    }
+
+    /// Возвращает имена полей схемы
+    pub fn names(&self) -> Vec<String> {
+        self.fields.iter().map(|(field, _)| field.clone()).collect()
+    }
+}
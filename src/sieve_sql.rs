@@ -0,0 +1,573 @@
+use super::field_value::FieldValue;
+use super::filter_operator::FilterOperator;
+use super::filter_term::FilterTerm;
+use super::schema_error::SchemaError;
+use super::sieve_model::SieveModel;
+use super::sieve_regex::IDENTIFIER_PATTERN;
+use super::sieve_schema::SieveSchema;
+use super::sort_order::SortOrder;
+use super::sort_term::SortTerm;
+use super::sql_dialect::SqlDialect;
+use super::sql_parts::SqlParts;
+
+/// Генерация SQL для SieveModel
+impl SieveModel {
+    /// Возвращает составные части SQL-запроса для указанного диалекта,
+    /// не встраивая их в базовый запрос
+    ///
+    /// Поля, имя которых не является корректным SQL-идентификатором,
+    /// пропускаются, поэтому в текст запроса не попадает пользовательский ввод.
+    /// Имена полей заключаются в кавычки диалекта: зарезервированные слова
+    /// (`order`) допустимы, а регистр имени учитывается.
+    ///
+    /// # Примеры
+    /// ```
+    /// # use sieve::{SieveModel, SqlDialect};
+    /// let model = SieveModel::new(
+    ///     &Some(2),
+    ///     &Some(20),
+    ///     &Some("title@=*Rock,year>=2000".to_string()),
+    ///     &Some("-year,title".to_string())
+    /// );
+    /// let parts = model.describe_sql(SqlDialect::Postgres);
+    /// assert_eq!(parts.where_clause(), Some(r#""title" ILIKE $1 ESCAPE '\' AND "year" >= $2"#.to_string()));
+    /// assert_eq!(parts.params(), vec!["%Rock%", "2000"]);
+    /// assert_eq!(parts.order_by(), Some(r#""year" DESC, "title" ASC"#.to_string()));
+    /// assert_eq!(parts.limit(), 20);
+    /// assert_eq!(parts.offset(), 20);
+    ///
+    /// let parts = model.describe_sql(SqlDialect::Sqlite);
+    /// assert_eq!(parts.where_clause(), Some(r#"LOWER("title") LIKE LOWER(?) ESCAPE '\' AND "year" >= ?"#.to_string()));
+    ///
+    /// // несколько полей: для `@=` достаточно совпадения одного, для `!=` должны отличаться все
+    /// let model = SieveModel::new(&None, &None, &Some("(title|artist)@=*Queen".to_string()), &None);
+    /// let parts = model.describe_sql(SqlDialect::Postgres);
+    /// assert_eq!(parts.where_clause(), Some(r#"("title" ILIKE $1 ESCAPE '\' OR "artist" ILIKE $1 ESCAPE '\')"#.to_string()));
+    ///
+    /// // несколько полей и несколько значений: одна группа OR из всех сочетаний
+    /// let model = SieveModel::new(&None, &None, &Some("(title|artist)@=*rock|jazz".to_string()), &None);
+    /// let parts = model.describe_sql(SqlDialect::Postgres);
+    /// assert_eq!(
+    ///     parts.where_clause(),
+    ///     Some(r#"("title" ILIKE $1 ESCAPE '\' OR "artist" ILIKE $1 ESCAPE '\' OR "title" ILIKE $2 ESCAPE '\' OR "artist" ILIKE $2 ESCAPE '\')"#.to_string())
+    /// );
+    /// assert_eq!(parts.params(), vec!["%rock%", "%jazz%"]);
+    /// let parts = model.describe_sql(SqlDialect::Sqlite);
+    /// assert_eq!(
+    ///     parts.where_clause(),
+    ///     Some(r#"(LOWER("title") LIKE LOWER(?) ESCAPE '\' OR LOWER("artist") LIKE LOWER(?) ESCAPE '\' OR LOWER("title") LIKE LOWER(?) ESCAPE '\' OR LOWER("artist") LIKE LOWER(?) ESCAPE '\')"#.to_string())
+    /// );
+    /// assert_eq!(parts.params(), vec!["%rock%", "%rock%", "%jazz%", "%jazz%"]);
+    /// 
+    /// let model = SieveModel::new(&None, &None, &Some("(title|artist)!=Queen".to_string()), &None);
+    /// let parts = model.describe_sql(SqlDialect::Postgres);
+    /// assert_eq!(parts.where_clause(), Some(r#"("title" <> $1 AND "artist" <> $1)"#.to_string()));
+    /// assert_eq!(parts.params(), vec!["Queen"]);
+    ///
+    /// // инвертированное условие
+    /// let model = SieveModel::new(&None, &None, &Some("title!@=*Rock,(title|artist)_=!The".to_string()), &None);
+    /// let parts = model.describe_sql(SqlDialect::Postgres);
+    /// assert_eq!(parts.where_clause(), Some(r#"NOT ("title" ILIKE $1 ESCAPE '\') AND NOT ("title" LIKE $2 ESCAPE '\' OR "artist" LIKE $2 ESCAPE '\')"#.to_string()));
+    ///
+    /// // `%`, `_` и `\` в значении сравниваются как текст, как и в памяти
+    /// # use sieve::{filter_items, FieldValue};
+    /// let model = SieveModel::new(&None, &None, &Some("title@=100%,title_=a_b".to_string()), &None);
+    /// let titles = filter_items(vec!["a_b 100%", "axb 100%", "a_b 1000"], &model, |title, _| Some(FieldValue::from(*title)));
+    /// assert_eq!(titles, vec!["a_b 100%"]);
+    /// let parts = model.describe_sql(SqlDialect::Postgres);
+    /// assert_eq!(parts.params(), vec![r"%100\%%", r"a\_b%"]);
+    /// assert_eq!(parts.where_clause(), Some(r#""title" LIKE $1 ESCAPE '\' AND "title" LIKE $2 ESCAPE '\'"#.to_string()));
+    /// assert_eq!(model.describe_sql(SqlDialect::MySql).where_clause(), Some("`title` LIKE ? AND `title` LIKE ?".to_string()));
+    /// assert_eq!(model.to_sql(&["title"]).1, vec![r"%100\%%", r"a\_b%"]);
+    ///
+    /// // логические значения с типом `:bool` передаются в записи диалекта
+    /// let model = SieveModel::new(&None, &None, &Some("active==yes:bool".to_string()), &None);
+    /// assert_eq!(model.describe_sql(SqlDialect::Postgres).params(), vec!["true"]);
+    /// assert_eq!(model.describe_sql(SqlDialect::MySql).params(), vec!["1"]);
+    /// assert_eq!(model.describe_sql(SqlDialect::Sqlite).params(), vec!["1"]);
+    ///
+    /// // зарезервированные слова в именах полей
+    /// let model = SieveModel::new(&None, &None, &Some("order>5".to_string()), &Some("-order".to_string()));
+    /// let parts = model.describe_sql(SqlDialect::MySql);
+    /// assert_eq!(parts.where_clause(), Some("`order` > ?".to_string()));
+    /// assert_eq!(parts.order_by(), Some("`order` DESC".to_string()));
+    /// assert_eq!(model.to_sql(&["order"]).0, r#""order" > $1"#);
+    /// assert_eq!(model.order_by_sql(&["order"]), r#""order" DESC"#);
+    /// ```
+    pub fn describe_sql(&self, dialect: SqlDialect) -> SqlParts {
+        let filters = self.filters().unwrap_or_default();
+        let sorts = self.sorts().unwrap_or_default();
+        let (where_clause, params) = where_sql(&filters, dialect);
+
+        SqlParts::new(where_clause, params, order_by_sql(&sorts, dialect), self.page_size(), self.offset(), self.distinct())
+    }
+
+    /// Оборачивает базовый запрос в подзапрос и применяет фильтры, сортировку
+    /// и пагинацию во внешнем `SELECT`
+    /// 
+    /// Так фильтры и сортировка могут ссылаться на вычисляемые столбцы
+    /// базового запроса. `base` подставляется как есть и не должен
+    /// содержать собственных плейсхолдеров.
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use sieve::{SieveModel, SqlDialect};
+    /// let base = "SELECT artist, COUNT(*) AS tracks FROM songs GROUP BY artist";
+    /// let model = SieveModel::new(&Some(2), &Some(10), &Some("tracks>=5".to_string()), &Some("-tracks".to_string()));
+    /// 
+    /// let (sql, params) = model.to_sql_with_base(base, SqlDialect::Postgres);
+    /// assert_eq!(
+    ///     sql,
+    ///     "SELECT * FROM (SELECT artist, COUNT(*) AS tracks FROM songs GROUP BY artist) t \
+    ///      WHERE \"tracks\" >= $1 ORDER BY \"tracks\" DESC LIMIT 10 OFFSET 10"
+    /// );
+    /// assert_eq!(params, vec!["5"]);
+    /// 
+    /// let model = SieveModel::new(&None, &Some(5), &None, &None);
+    /// let (sql, params) = model.to_sql_with_base("SELECT * FROM songs", SqlDialect::Sqlite);
+    /// assert_eq!(sql, "SELECT * FROM (SELECT * FROM songs) t LIMIT 5 OFFSET 0");
+    /// assert!(params.is_empty());
+    /// ```
+    pub fn to_sql_with_base(&self, base: &str, dialect: SqlDialect) -> (String, Vec<String>) {
+        let parts = self.describe_sql(dialect);
+        (parts.to_select(&format!("({}) t", base)), parts.params())
+    }
+
+    /// Возвращает условие keyset-пагинации: записи строго после `last_key`
+    /// в порядке сортировки модели, и значения его параметров
+    /// 
+    /// `last_key` - значения столбцов сортировки последней записи предыдущей
+    /// страницы (последним столбцом сортировки обычно указывают уникальный `id`).
+    /// `first_index` - номер первого плейсхолдера (для Postgres): чтобы
+    /// объединить условие с фильтрами через `AND`, передают количество
+    /// параметров фильтров плюс один.
+    /// Если все столбцы сортируются в одном направлении, строится сравнение
+    /// кортежей, иначе - эквивалентное условие через `OR`. Идентификаторы
+    /// заключаются в кавычки диалекта. Возвращает `None`, если сортировки нет,
+    /// количество значений не совпадает с количеством столбцов или имя
+    /// столбца недопустимо.
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use sieve::{SieveModel, SqlDialect};
+    /// let model = SieveModel::new(&None, &None, &None, &Some("name".to_string()));
+    /// assert_eq!(
+    ///     model.keyset_where(&["M"], SqlDialect::Postgres, 1),
+    ///     Some((r#""name" > $1"#.to_string(), vec!["M".to_string()]))
+    /// );
+    /// 
+    /// let model = SieveModel::new(&None, &None, &None, &Some("name,id".to_string()));
+    /// assert_eq!(
+    ///     model.keyset_where(&["M", "42"], SqlDialect::Sqlite, 1),
+    ///     Some((r#"("name", "id") > (?, ?)"#.to_string(), vec!["M".to_string(), "42".to_string()]))
+    /// );
+    /// 
+    /// let model = SieveModel::new(&None, &None, &None, &Some("-year,id".to_string()));
+    /// let (sql, params) = model.keyset_where(&["1975", "7"], SqlDialect::MySql, 1).unwrap();
+    /// assert_eq!(sql, "(`year` < ? OR (`year` = ? AND `id` > ?))");
+    /// assert_eq!(params, vec!["1975", "1975", "7"]);
+    /// 
+    /// assert_eq!(model.keyset_where(&["1975"], SqlDialect::Postgres, 1), None);
+    /// 
+    /// // условие продолжает нумерацию параметров фильтров
+    /// let model = SieveModel::new(&None, &None, &Some("artist==Queen".to_string()), &Some("-year,id".to_string()));
+    /// let parts = model.describe_sql(SqlDialect::Postgres);
+    /// let (keyset, keyset_params) = model.keyset_where(&["1975", "7"], SqlDialect::Postgres, parts.params().len() + 1).unwrap();
+    /// let sql = format!("{} AND {}", parts.where_clause().unwrap(), keyset);
+    /// assert_eq!(sql, r#""artist" = $1 AND ("year" < $2 OR ("year" = $3 AND "id" > $4))"#);
+    /// assert_eq!([parts.params(), keyset_params].concat(), vec!["Queen", "1975", "1975", "7"]);
+    /// 
+    /// // строковые значения сравниваются лексикографически и в памяти
+    /// # use sieve::{filter_items, FieldValue};
+    /// let sieve = SieveModel::new(&None, &None, &Some("name>'M'".to_string()), &None);
+    /// let names = filter_items(vec!["Adele", "Madonna", "Nirvana", "Queen"], &sieve, |name, _| Some(FieldValue::from(*name)));
+    /// assert_eq!(names, vec!["Madonna", "Nirvana", "Queen"]);
+    /// assert_eq!(sieve.describe_sql(SqlDialect::Postgres).params(), vec!["M"]);
+    /// ```
+    pub fn keyset_where(&self, last_key: &[&str], dialect: SqlDialect, first_index: usize) -> Option<(String, Vec<String>)> {
+        let sorts = self.sorts().unwrap_or_default();
+        if sorts.is_empty() || sorts.len() != last_key.len() || !sorts.iter().all(|sort| is_identifier(&sort.name())) {
+            return None;
+        }
+
+        let mut params = Vec::new();
+        let mut placeholder = |value: &str, case_insensitive: bool| {
+            params.push(value.to_string());
+            let placeholder = dialect.placeholder(first_index + params.len() - 1);
+            if case_insensitive { format!("LOWER({})", placeholder) } else { placeholder }
+        };
+        let column = |sort: &SortTerm| {
+            let column = dialect.quote_identifier(&sort.name());
+            if sort.case_insensitive() { format!("LOWER({})", column) } else { column }
+        };
+        let comparison = |order: SortOrder| match order {
+            SortOrder::Ascending => ">",
+            SortOrder::Descending => "<",
+        };
+
+        let order = sorts[0].order();
+        if sorts.iter().all(|sort| sort.order() == order) {
+            let columns: Vec<String> = sorts.iter().map(column).collect();
+            let placeholders: Vec<String> = sorts.iter().zip(last_key).map(|(sort, value)| placeholder(value, sort.case_insensitive())).collect();
+            let sql = if sorts.len() == 1 {
+                format!("{} {} {}", columns[0], comparison(order), placeholders[0])
+            } else {
+                format!("({}) {} ({})", columns.join(", "), comparison(order), placeholders.join(", "))
+            };
+            return Some((sql, params));
+        }
+
+        // Разные направления: (a > ?) OR (a = ? AND b < ?) OR ...
+        let mut groups = Vec::new();
+        for index in 0..sorts.len() {
+            let mut conditions: Vec<String> = sorts[..index]
+                .iter()
+                .zip(last_key)
+                .map(|(sort, value)| format!("{} = {}", column(sort), placeholder(value, sort.case_insensitive())))
+                .collect();
+            let sort = &sorts[index];
+            conditions.push(format!("{} {} {}", column(sort), comparison(sort.order()), placeholder(last_key[index], sort.case_insensitive())));
+            groups.push(if conditions.len() == 1 { conditions.remove(0) } else { format!("({})", conditions.join(" AND ")) });
+        }
+        Some((format!("({})", groups.join(" OR ")), params))
+    }
+
+    /// Возвращает условие WHERE для Postgres (без ключевого слова `WHERE`)
+    /// и значения параметров `$1`, `$2`, ... в порядке их следования
+    /// 
+    /// В условие попадают только поля из списка `allowed`: остальные поля
+    /// отбрасываются, а терм без разрешенных полей пропускается целиком.
+    /// Имена полей никогда не передаются в параметрах. Если условий нет,
+    /// возвращается пустая строка.
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use sieve::SieveModel;
+    /// let allowed = ["title", "artist", "year"];
+    /// let model = SieveModel::new(
+    ///     &None,
+    ///     &None,
+    ///     &Some("(title|artist)@=*queen,genre==rock|jazz,year>=1975,title_=The,artist_-=band,password==x".to_string()),
+    ///     &None
+    /// );
+    /// let (sql, params) = model.to_sql(&allowed);
+    /// assert_eq!(sql, r#"("title" ILIKE $1 ESCAPE '\' OR "artist" ILIKE $1 ESCAPE '\') AND "year" >= $2 AND "title" LIKE $3 ESCAPE '\' AND "artist" LIKE $4 ESCAPE '\'"#);
+    /// assert_eq!(params, vec!["%queen%", "1975", "The%", "%band"]);
+    /// 
+    /// // несколько значений дают группу через OR
+    /// let model = SieveModel::new(&None, &None, &Some("artist==Queen|Eagles,title!=x".to_string()), &None);
+    /// let (sql, params) = model.to_sql(&allowed);
+    /// assert_eq!(sql, r#"("artist" = $1 OR "artist" = $2) AND "title" <> $3"#);
+    /// assert_eq!(params, vec!["Queen", "Eagles", "x"]);
+    /// 
+    /// // поля вне списка не попадают в запрос
+    /// let model = SieveModel::new(&None, &None, &Some("(title|id; DROP TABLE users)==x,1=1==1".to_string()), &None);
+    /// assert_eq!(model.to_sql(&allowed), (r#""title" = $1"#.to_string(), vec!["x".to_string()]));
+    /// assert_eq!(model.to_sql(&[]), (String::new(), vec![]));
+    /// ```
+    pub fn to_sql(&self, allowed: &[&str]) -> (String, Vec<String>) {
+        let filters: Vec<FilterTerm> = self
+            .filters()
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|term| {
+                let names: Vec<String> = term.names().into_iter().filter(|name| allowed.contains(&name.as_str())).collect();
+                (!names.is_empty()).then(|| term.with_names(names))
+            })
+            .collect();
+        let (where_clause, params) = where_sql(&filters, SqlDialect::Postgres);
+        (where_clause.unwrap_or_default(), params)
+    }
+
+    /// Возвращает выражение ORDER BY (без ключевых слов `ORDER BY`)
+    /// только по полям из списка `allowed`
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use sieve::SieveModel;
+    /// let model = SieveModel::new(&None, &None, &None, &Some("-year,secret,title".to_string()));
+    /// assert_eq!(model.order_by_sql(&["title", "year"]), r#""year" DESC, "title" ASC"#);
+    /// assert_eq!(model.order_by_sql(&[]), "");
+    /// 
+    /// let model = SieveModel::new(&None, &None, &None, &Some("title:ci,-year".to_string()));
+    /// assert_eq!(model.order_by_sql(&["title", "year"]), r#"LOWER("title") ASC, "year" DESC"#);
+    /// ```
+    pub fn order_by_sql(&self, allowed: &[&str]) -> String {
+        let sorts: Vec<SortTerm> = self
+            .sorts()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|sort| allowed.contains(&sort.name().as_str()))
+            .collect();
+        order_by_sql(&sorts, SqlDialect::Postgres).unwrap_or_default()
+    }
+
+    /// Возвращает фильтры, которые генератор SQL может точно выразить в указанном диалекте
+    /// 
+    /// Остальные фильтры (поля с именами, недопустимыми в SQL, или
+    /// регистронезависимое сравнение, которое диалект не поддерживает)
+    /// нужно применять к результату в памяти.
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use sieve::{SieveModel, SqlDialect};
+    /// let model = SieveModel::new(&None, &None, &Some("title@=*Ёлка,year>2000,\\@tag==x".to_string()), &None);
+    /// 
+    /// let names = |dialect| model.filters_sql_only(dialect).iter().map(|term| term.names()[0].clone()).collect::<Vec<_>>();
+    /// assert_eq!(names(SqlDialect::Postgres), vec!["title", "year"]);
+    /// assert_eq!(names(SqlDialect::Sqlite), vec!["year"]);
+    /// ```
+    pub fn filters_sql_only(&self, dialect: SqlDialect) -> Vec<FilterTerm> {
+        self.filters()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|term| is_expressible(term, dialect))
+            .collect()
+    }
+
+    /// Проверяет модель перед генерацией SQL, ничего не выполняя
+    /// 
+    /// Каждое поле фильтров и сортировки должно быть в схеме и иметь имя,
+    /// допустимое для SQL (иначе генератор SQL молча пропустит его),
+    /// а каждый оператор - совместим с типом поля. Возвращает все найденные ошибки.
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use sieve::{FieldType, FilterOperator, SchemaError, SieveModel, SieveSchema};
+    /// let schema = SieveSchema::new()
+    ///     .field("title", FieldType::Text)
+    ///     .field("year", FieldType::Number);
+    /// 
+    /// let model = SieveModel::new(&None, &None, &Some("title@=Rock,year>2000".to_string()), &Some("-year".to_string()));
+    /// assert!(model.validate_sql(&schema).is_ok());
+    /// 
+    /// let model = SieveModel::new(&None, &None, &Some("password==x,year@=19".to_string()), &Some("secret".to_string()));
+    /// assert_eq!(model.validate_sql(&schema), Err(vec![
+    ///     SchemaError::UnknownField("password".to_string()),
+    ///     SchemaError::IncompatibleOperator { field: "year".to_string(), operator: FilterOperator::Contains },
+    ///     SchemaError::UnknownField("secret".to_string()),
+    /// ]));
+    /// 
+    /// // поле из схемы с именем, недопустимым в SQL, не попадет в запрос
+    /// let schema = schema.field("@tag", FieldType::Text).field("play-count", FieldType::Number);
+    /// let model = SieveModel::new(&None, &None, &Some("\\@tag==x".to_string()), &Some("play-count".to_string()));
+    /// assert_eq!(model.validate_sql(&schema), Err(vec![
+    ///     SchemaError::InvalidIdentifier("@tag".to_string()),
+    ///     SchemaError::InvalidIdentifier("play-count".to_string()),
+    /// ]));
+    /// ```
+    pub fn validate_sql(&self, schema: &SieveSchema) -> Result<(), Vec<SchemaError>> {
+        let mut errors = Vec::new();
+        for term in self.filters().unwrap_or_default() {
+            let operator = term.operator();
+            for name in term.names() {
+                match schema.field_type(&name) {
+                    None => errors.push(SchemaError::UnknownField(name)),
+                    Some(_) if !is_identifier(&name) => errors.push(SchemaError::InvalidIdentifier(name)),
+                    Some(field_type) if !field_type.supports(&operator) => {
+                        errors.push(SchemaError::IncompatibleOperator { field: name, operator: operator.clone() });
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+        for sort in self.sorts().unwrap_or_default() {
+            if !schema.contains(&sort.name()) {
+                errors.push(SchemaError::UnknownField(sort.name()));
+            } else if !is_identifier(&sort.name()) {
+                errors.push(SchemaError::InvalidIdentifier(sort.name()));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+/// Проверяет, что имя поля можно безопасно подставить в текст запроса
+fn is_identifier(name: &str) -> bool {
+    IDENTIFIER_PATTERN.is_match(name)
+}
+
+/// Проверяет, что терм можно точно выразить в SQL указанного диалекта
+fn is_expressible(term: &FilterTerm, dialect: SqlDialect) -> bool {
+    term.names().iter().all(|name| is_identifier(name))
+        && (!term.case_insensitive() || term.values().iter().all(|value| dialect.supports_case_folding(value)))
+}
+
+/// Строит условие WHERE из списка фильтров, объединяя термы через `AND`
+/// 
+/// Единственное равенство `поле==значение` строится напрямую, без общего пути.
+/// 
+/// # Примеры
+/// ```
+/// # use sieve::{SieveModel, SqlDialect};
+/// let sql = |filters: &str| {
+///     let parts = SieveModel::new(&None, &None, &Some(filters.to_string()), &None).describe_sql(SqlDialect::Postgres);
+///     (parts.where_clause().unwrap(), parts.params())
+/// };
+/// // быстрый путь совпадает с началом условия, построенного общим путем
+/// let (fast, fast_params) = sql("artist==Queen");
+/// let (general, general_params) = sql("artist==Queen,year>1");
+/// assert_eq!(fast, r#""artist" = $1"#);
+/// assert!(general.starts_with(&format!("{} AND ", fast)));
+/// assert_eq!(fast_params[..], general_params[..1]);
+/// 
+/// assert_eq!(sql("artist==*Queen").0, r#"LOWER("artist") = LOWER($1)"#);
+/// ```
+fn where_sql(filters: &[FilterTerm], dialect: SqlDialect) -> (Option<String>, Vec<String>) {
+    if let [term] = filters {
+        if let Some((name, value)) = term.single_equality().filter(|(name, _)| is_identifier(name)) {
+            let placeholder = dialect.placeholder(1);
+            let condition = condition_sql(&dialect.quote_identifier(name), &FilterOperator::Equals, term.case_insensitive(), &placeholder, dialect);
+            return (Some(condition), vec![value.to_string()]);
+        }
+    }
+
+    let mut params = Vec::new();
+    let conditions: Vec<String> = filters
+        .iter()
+        .filter_map(|term| term_sql(term, dialect, &mut params))
+        .collect();
+
+    if conditions.is_empty() {
+        (None, params)
+    } else {
+        (Some(conditions.join(" AND ")), params)
+    }
+}
+
+/// Строит условие для одного терма
+///
+/// Несколько полей и несколько значений дают группу условий, объединенных через `OR`.
+/// Для `!=` группа объединяется через `AND`: все поля должны отличаться от всех значений.
+fn term_sql(term: &FilterTerm, dialect: SqlDialect, params: &mut Vec<String>) -> Option<String> {
+    let names: Vec<String> = term
+        .names()
+        .into_iter()
+        .filter(|name| is_identifier(name))
+        .map(|name| dialect.quote_identifier(&name))
+        .collect();
+    if names.is_empty() {
+        return None;
+    }
+
+    let operator = term.operator();
+    let mut conditions = Vec::new();
+    for value in term.values() {
+        if let Some(condition) = null_condition(&operator, term.is_null_value(&value)) {
+            conditions.extend(names.iter().map(|name| format!("{} {}", name, condition)));
+            continue;
+        }
+
+        let param = bind_value(&operator, &sql_value(term, &value, dialect));
+        let mut shared = None;
+        for name in &names {
+            let placeholder = match shared.clone() {
+                Some(placeholder) => placeholder,
+                None => {
+                    params.push(param.clone());
+                    let placeholder = dialect.placeholder(params.len());
+                    if dialect.numbered_placeholders() {
+                        shared = Some(placeholder.clone());
+                    }
+                    placeholder
+                }
+            };
+            conditions.push(condition_sql(name, &operator, term.case_insensitive(), &placeholder, dialect));
+        }
+    }
+
+    let separator = match operator {
+        FilterOperator::NotEquals => " AND ",
+        _ => " OR ",
+    };
+    let group = if conditions.len() == 1 {
+        conditions.pop()?
+    } else {
+        format!("({})", conditions.join(separator))
+    };
+    if term.negated() {
+        Some(format!("NOT {}", if group.starts_with('(') { group } else { format!("({})", group) }))
+    } else {
+        Some(group)
+    }
+}
+
+/// Возвращает проверку на `NULL` для `==$null` и `!=$null`
+fn null_condition(operator: &FilterOperator, is_null: bool) -> Option<&'static str> {
+    match (operator, is_null) {
+        (FilterOperator::Equals, true) => Some("IS NULL"),
+        (FilterOperator::NotEquals, true) => Some("IS NOT NULL"),
+        _ => None,
+    }
+}
+
+/// Приводит значение фильтра к его явному типу в записи диалекта (`1:bool` - `true` или `1`)
+fn sql_value(term: &FilterTerm, value: &str, dialect: SqlDialect) -> String {
+    match term.value_type().and_then(|value_type| value_type.coerce(value)) {
+        Some(FieldValue::Bool(value)) => dialect.bool_literal(value).to_string(),
+        _ => term.normalized_value(value),
+    }
+}
+
+/// Преобразует значение фильтра в значение параметра (шаблон для LIKE)
+/// 
+/// В шаблоне LIKE символы `%`, `_` и `\` экранируются обратным слешем,
+/// поэтому сравниваются как обычный текст, как и при фильтрации в памяти.
+fn bind_value(operator: &FilterOperator, value: &str) -> String {
+    let escape = |value: &str| value.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_");
+    match operator {
+        FilterOperator::Contains => format!("%{}%", escape(value)),
+        FilterOperator::StartsWith => format!("{}%", escape(value)),
+        FilterOperator::EndsWith => format!("%{}", escape(value)),
+        _ => value.to_string(),
+    }
+}
+
+/// Строит условие сравнения одного поля с плейсхолдером
+fn condition_sql(name: &str, operator: &FilterOperator, case_insensitive: bool, placeholder: &str, dialect: SqlDialect) -> String {
+    let sql_operator = match operator {
+        FilterOperator::Equals => "=",
+        FilterOperator::NotEquals => "<>",
+        FilterOperator::GreaterThan => ">",
+        FilterOperator::LessThan => "<",
+        FilterOperator::GreaterThanOrEqualTo => ">=",
+        FilterOperator::LessThanOrEqualTo => "<=",
+        FilterOperator::Contains | FilterOperator::StartsWith | FilterOperator::EndsWith => "LIKE",
+    };
+
+    let escape = if sql_operator == "LIKE" { dialect.like_escape() } else { "" };
+    if !case_insensitive {
+        format!("{} {} {}{}", name, sql_operator, placeholder, escape)
+    } else if sql_operator == "LIKE" && dialect.supports_ilike() {
+        format!("{} ILIKE {}{}", name, placeholder, escape)
+    } else {
+        format!("LOWER({}) {} LOWER({}){}", name, sql_operator, placeholder, escape)
+    }
+}
+
+/// Строит выражение ORDER BY из списка термов сортировки
+fn order_by_sql(sorts: &[SortTerm], dialect: SqlDialect) -> Option<String> {
+    let columns: Vec<String> = sorts
+        .iter()
+        .filter(|sort| is_identifier(&sort.name()))
+        .map(|sort| {
+            let column = dialect.quote_identifier(&sort.name());
+            let column = if sort.case_insensitive() { format!("LOWER({})", column) } else { column };
+            match sort.order() {
+                SortOrder::Ascending => format!("{} ASC", column),
+                SortOrder::Descending => format!("{} DESC", column),
+            }
+        })
+        .collect();
+
+    if columns.is_empty() {
+        None
+    } else {
+        Some(columns.join(", "))
+    }
+}
@@ -0,0 +1,50 @@
+use super::field_value::FieldValue;
+
+/// Запись, поля которой доступны по имени для фильтрации и сортировки в памяти
+/// 
+/// Реализация трейта позволяет применять SieveModel к `Vec<T>` без
+/// [`SieveConfig`](super::sieve_config::SieveConfig): неизвестные поля
+/// (`None`) пропускаются в фильтрах и не меняют порядок при сортировке.
+/// 
+/// # Примеры
+/// ```
+/// # use sieve::{FieldValue, SieveFilter, SieveModel, SieveOrder, SievePaginate, Sieveable};
+/// #[derive(Debug, PartialEq)]
+/// struct Track { title: String, artist: String, year: i64 }
+/// 
+/// impl Sieveable for Track {
+///     fn field(&self, name: &str) -> Option<FieldValue> {
+///         match name {
+///             "title" => Some(FieldValue::from(self.title.as_str())),
+///             "artist" => Some(FieldValue::from(self.artist.as_str())),
+///             "year" => Some(FieldValue::from(self.year)),
+///             _ => None,
+///         }
+///     }
+/// }
+/// 
+/// let track = |title: &str, artist: &str, year| Track { title: title.to_string(), artist: artist.to_string(), year };
+/// let tracks = vec![
+///     track("Bohemian Rhapsody", "Queen", 1975),
+///     track("Rock You Like a Hurricane", "Scorpions", 1984),
+///     track("Queen of the Night", "Whitney Houston", 1992),
+///     track("Hotel California", "Eagles", 1976),
+/// ];
+/// 
+/// // несколько полей и несколько значений объединяются через ИЛИ,
+/// // неизвестное поле `rating` пропускается
+/// let sieve = SieveModel::new(
+///     &Some(1),
+///     &Some(2),
+///     &Some("(title|artist)@=Queen|Hotel,year>=1975,rating>3".to_string()),
+///     &Some("-year".to_string())
+/// );
+/// let page = tracks.filter_with(&sieve).order_with(&sieve).paginate_with(&sieve);
+/// let titles: Vec<&str> = page.items().iter().map(|track| track.title.as_str()).collect();
+/// assert_eq!(titles, vec!["Queen of the Night", "Hotel California"]);
+/// assert_eq!(page.total(), 3);
+/// ```
+pub trait Sieveable {
+    /// Возвращает значение поля по имени или `None`, если такого поля нет
+    fn field(&self, name: &str) -> Option<FieldValue>;
+}
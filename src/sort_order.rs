@@ -1,5 +1,5 @@
 /// Порядок сортировки
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum SortOrder {
     /// По возрастанию
     Ascending,
@@ -7,3 +7,23 @@ pub enum SortOrder {
     /// По убыванию
     Descending,
 }
+
+/// Реализация методов для SortOrder
+impl SortOrder {
+    /// Возвращает порядок сортировки по символу-префиксу поля
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use sieve::SortOrder;
+    /// assert_eq!(SortOrder::from_prefix('-'), Some(SortOrder::Descending));
+    /// assert_eq!(SortOrder::from_prefix('+'), Some(SortOrder::Ascending));
+    /// assert_eq!(SortOrder::from_prefix('t'), None);
+    /// ```
+    pub fn from_prefix(prefix: char) -> Option<SortOrder> {
+        match prefix {
+            '-' => Some(SortOrder::Descending),
+            '+' => Some(SortOrder::Ascending),
+            _ => None,
+        }
+    }
+}
@@ -5,7 +5,9 @@ use super::sort_order::SortOrder;
 /// 
 /// # Примеры
 /// ```
-/// let sort = SortTerm::from_str("-created_time");
+/// # use std::str::FromStr;
+/// # use sieve::{SortTerm, SortOrder};
+/// let sort = SortTerm::from_str("-created_time").unwrap();
 /// assert_eq!(sort.name(), "created_time");
 /// assert_eq!(sort.order(), SortOrder::Descending);
 /// ```
@@ -28,10 +30,37 @@ pub struct SortTerm {
     /// - `SortOrder::Ascending` - сортировка по возрастанию
     /// - `SortOrder::Descending` - сортировка по убыванию
     order: SortOrder,
+
+    /// Флаг явного указания направления префиксом (`-` или `+`)
+    explicit_order: bool,
+
+    /// Флаг сортировки без учета регистра (`title:ci`)
+    case_insensitive: bool,
+
+    /// Приоритет терма среди остальных термов сортировки (`title:1`)
+    priority: Option<u32>,
 }
 
 /// Реализует методы для SortTerm
 impl SortTerm {
+    /// Создает терм сортировки без разбора строки
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use sieve::{SortTerm, SortOrder};
+    /// let sort = SortTerm::new("created_time", SortOrder::Descending);
+    /// assert_eq!(sort.name(), "created_time");
+    /// assert!(sort.explicit_order());
+    /// ```
+    pub fn new(name: &str, order: SortOrder) -> Self {
+        Self { name: name.to_string(), order, explicit_order: true, case_insensitive: false, priority: None }
+    }
+
+    /// Задает флаг сортировки без учета регистра
+    pub fn with_case_insensitive(mut self, case_insensitive: bool) -> Self {
+        self.case_insensitive = case_insensitive;
+        self
+    }
 
     /// Возвращает имя поля для сортировки
     /// 
@@ -53,6 +82,33 @@ impl SortTerm {
     pub fn order(&self) -> SortOrder {
         self.order.clone()
     }
+
+    /// Возвращает `true`, если направление сортировки указано префиксом (`-поле` или `+поле`)
+    pub fn explicit_order(&self) -> bool {
+        self.explicit_order
+    }
+
+    /// Возвращает `true`, если строки сравниваются без учета регистра (`title:ci`)
+    pub fn case_insensitive(&self) -> bool {
+        self.case_insensitive
+    }
+
+    /// Возвращает приоритет терма, если он указан (`title:1`)
+    /// 
+    /// Термы с меньшим приоритетом применяются раньше.
+    pub fn priority(&self) -> Option<u32> {
+        self.priority
+    }
+
+    /// Возвращает копию терма с другим именем поля
+    pub(crate) fn with_name(&self, name: &str) -> Self {
+        Self { name: name.to_string(), ..self.clone() }
+    }
+
+    /// Возвращает копию терма с другим направлением сортировки
+    pub(crate) fn with_order(&self, order: SortOrder) -> Self {
+        Self { order, ..self.clone() }
+    }
 }
 
 /// Реализует методы для SortTerm
@@ -63,28 +119,60 @@ impl FromStr for SortTerm {
     /// 
     /// # Примеры
     /// ```
-    /// let sort = SortTerm::new("-created_time");
+    /// # use std::str::FromStr;
+    /// # use sieve::{SortTerm, SortOrder};
+    /// let sort = SortTerm::from_str("-created_time").unwrap();
     /// assert_eq!(sort.name(), "created_time");
     /// assert_eq!(sort.order(), SortOrder::Descending);
     /// 
-    /// let sort = SortTerm::new("title");
+    /// let sort = SortTerm::from_str("title").unwrap();
+    /// assert_eq!(sort.name(), "title");
+    /// assert_eq!(sort.order(), SortOrder::Ascending);
+    /// assert!(!sort.explicit_order());
+    /// 
+    /// let sort = SortTerm::from_str("+title").unwrap();
     /// assert_eq!(sort.name(), "title");
     /// assert_eq!(sort.order(), SortOrder::Ascending);
+    /// assert!(sort.explicit_order());
+    /// 
+    /// // модификатор `:ci` включает сортировку без учета регистра
+    /// let sort = SortTerm::from_str("-title:ci").unwrap();
+    /// assert_eq!(sort.name(), "title");
+    /// assert_eq!(sort.order(), SortOrder::Descending);
+    /// assert!(sort.case_insensitive());
+    /// assert!(!SortTerm::from_str("title").unwrap().case_insensitive());
+    /// 
+    /// // числовой модификатор задает приоритет, модификаторы можно сочетать
+    /// let sort = SortTerm::from_str("-title:ci:2").unwrap();
+    /// assert_eq!(sort.name(), "title");
+    /// assert_eq!(sort.priority(), Some(2));
+    /// assert!(sort.case_insensitive());
+    /// assert_eq!(SortTerm::from_str("artist:1").unwrap().priority(), Some(1));
+    /// assert_eq!(SortTerm::from_str("artist:x").unwrap().name(), "artist:x");
     /// ```
     fn from_str(sort: &str) -> Result<Self, Self::Err> {
         if sort.trim().is_empty() {
             return Err("Sort is empty".to_string());
         }
 
-        let order = if sort.starts_with('-') {
-            SortOrder::Descending
-        } else {
-            SortOrder::Ascending
+        let prefix = sort.chars().next().and_then(SortOrder::from_prefix);
+        let explicit_order = prefix.is_some();
+        let (order, name) = match prefix {
+            Some(order) => (order, &sort[1..]),
+            None => (SortOrder::Ascending, sort),
         };
-        let name = match order {
-            SortOrder::Descending => sort[1..].to_string(),
-            SortOrder::Ascending => sort.to_string(),
-        };
-        Ok(Self { name, order })
+        // Модификаторы после двоеточия: `:ci` и числовой приоритет
+        let (mut name, mut case_insensitive, mut priority) = (name, false, None);
+        while let Some((head, modifier)) = name.rsplit_once(':') {
+            match modifier {
+                "ci" if !case_insensitive => case_insensitive = true,
+                _ => match modifier.parse::<u32>() {
+                    Ok(value) if priority.is_none() => priority = Some(value),
+                    _ => break,
+                },
+            }
+            name = head;
+        }
+        Ok(Self { name: name.to_string(), order, explicit_order, case_insensitive, priority })
     }
 }
\ No newline at end of file
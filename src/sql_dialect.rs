@@ -0,0 +1,120 @@
+/// Диалект SQL, определяющий синтаксис плейсхолдеров и операторов
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SqlDialect {
+    /// PostgreSQL (`$1`, `$2`, ..., `ILIKE`)
+    Postgres,
+
+    /// MySQL (`?`)
+    MySql,
+
+    /// SQLite (`?`)
+    Sqlite,
+}
+
+/// Реализация методов для SqlDialect
+impl SqlDialect {
+    /// Возвращает плейсхолдер для параметра с указанным номером (начиная с 1)
+    ///
+    /// # Примеры
+    /// ```
+    /// # use sieve::SqlDialect;
+    /// assert_eq!(SqlDialect::Postgres.placeholder(2), "$2");
+    /// assert_eq!(SqlDialect::MySql.placeholder(2), "?");
+    /// ```
+    pub fn placeholder(&self, index: usize) -> String {
+        match self {
+            SqlDialect::Postgres => format!("${}", index),
+            SqlDialect::MySql | SqlDialect::Sqlite => "?".to_string(),
+        }
+    }
+
+    /// Возвращает `true`, если плейсхолдеры нумерованные и один параметр
+    /// можно использовать в запросе несколько раз
+    pub fn numbered_placeholders(&self) -> bool {
+        matches!(self, SqlDialect::Postgres)
+    }
+
+    /// Возвращает `true`, если диалект корректно сравнивает значение без учета регистра
+    /// 
+    /// `LOWER()` в SQLite без расширения ICU меняет регистр только ASCII-символов.
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use sieve::SqlDialect;
+    /// assert!(SqlDialect::Sqlite.supports_case_folding("Rock"));
+    /// assert!(!SqlDialect::Sqlite.supports_case_folding("Ёлка"));
+    /// assert!(SqlDialect::Postgres.supports_case_folding("Ёлка"));
+    /// ```
+    pub fn supports_case_folding(&self, value: &str) -> bool {
+        match self {
+            SqlDialect::Sqlite => value.is_ascii(),
+            SqlDialect::Postgres | SqlDialect::MySql => true,
+        }
+    }
+
+    /// Заключает идентификатор в кавычки диалекта, удваивая кавычки внутри имени
+    /// 
+    /// Составное имя `таблица.столбец` заключается в кавычки по частям.
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use sieve::SqlDialect;
+    /// assert_eq!(SqlDialect::Postgres.quote_identifier("name"), "\"name\"");
+    /// assert_eq!(SqlDialect::Sqlite.quote_identifier("t.name"), "\"t\".\"name\"");
+    /// assert_eq!(SqlDialect::MySql.quote_identifier("order"), "`order`");
+    /// assert_eq!(SqlDialect::Postgres.quote_identifier("a\"b"), "\"a\"\"b\"");
+    /// ```
+    pub fn quote_identifier(&self, name: &str) -> String {
+        let quote = match self {
+            SqlDialect::Postgres | SqlDialect::Sqlite => '"',
+            SqlDialect::MySql => '`',
+        };
+        name.split('.')
+            .map(|part| format!("{quote}{}{quote}", part.replace(quote, &format!("{quote}{quote}"))))
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+
+    /// Возвращает указание экранирующего символа для шаблонов LIKE
+    /// 
+    /// В MySQL обратный слеш экранирует шаблон по умолчанию, в Postgres
+    /// и SQLite он задается явно через `ESCAPE`.
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use sieve::SqlDialect;
+    /// assert_eq!(SqlDialect::Postgres.like_escape(), " ESCAPE '\\'");
+    /// assert_eq!(SqlDialect::MySql.like_escape(), "");
+    /// ```
+    pub fn like_escape(&self) -> &'static str {
+        match self {
+            SqlDialect::Postgres | SqlDialect::Sqlite => " ESCAPE '\\'",
+            SqlDialect::MySql => "",
+        }
+    }
+
+    /// Возвращает значение параметра для логического значения
+    /// 
+    /// В MySQL и SQLite нет отдельного логического типа, столбцы хранят `1` и `0`.
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use sieve::SqlDialect;
+    /// assert_eq!(SqlDialect::Postgres.bool_literal(true), "true");
+    /// assert_eq!(SqlDialect::MySql.bool_literal(true), "1");
+    /// assert_eq!(SqlDialect::Sqlite.bool_literal(false), "0");
+    /// ```
+    pub fn bool_literal(&self, value: bool) -> &'static str {
+        match (self, value) {
+            (SqlDialect::Postgres, true) => "true",
+            (SqlDialect::Postgres, false) => "false",
+            (SqlDialect::MySql | SqlDialect::Sqlite, true) => "1",
+            (SqlDialect::MySql | SqlDialect::Sqlite, false) => "0",
+        }
+    }
+
+    /// Возвращает `true`, если диалект поддерживает `ILIKE`
+    pub fn supports_ilike(&self) -> bool {
+        matches!(self, SqlDialect::Postgres)
+    }
+}
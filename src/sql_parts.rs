@@ -0,0 +1,108 @@
+/// Составные части SQL-запроса, построенные по SieveModel
+///
+/// Значения фильтров никогда не подставляются в текст условия,
+/// а передаются отдельно в `params` в порядке следования плейсхолдеров.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SqlParts {
+    /// Условие WHERE без ключевого слова `WHERE`
+    where_clause: Option<String>,
+
+    /// Значения параметров в порядке плейсхолдеров
+    params: Vec<String>,
+
+    /// Выражение ORDER BY без ключевых слов `ORDER BY`
+    order_by: Option<String>,
+
+    /// Значение LIMIT
+    limit: u64,
+
+    /// Значение OFFSET
+    offset: u64,
+
+    /// Флаг `SELECT DISTINCT`
+    distinct: bool,
+}
+
+/// Реализация методов для SqlParts
+impl SqlParts {
+    pub fn new(where_clause: Option<String>, params: Vec<String>, order_by: Option<String>, limit: u64, offset: u64, distinct: bool) -> Self {
+        Self { where_clause, params, order_by, limit, offset, distinct }
+    }
+
+    /// Возвращает условие WHERE
+    ///
+    /// # Примеры
+    /// - `Some(r#""title" ILIKE $1 ESCAPE '\' AND "year" >= $2"#)`
+    /// - `None` - фильтры не заданы
+    pub fn where_clause(&self) -> Option<String> {
+        self.where_clause.clone()
+    }
+
+    /// Возвращает значения параметров
+    ///
+    /// # Примеры
+    /// - `["%Rock%", "2000"]`
+    pub fn params(&self) -> Vec<String> {
+        self.params.clone()
+    }
+
+    /// Возвращает выражение ORDER BY
+    ///
+    /// # Примеры
+    /// - `Some(r#""year" DESC, "title" ASC"#)`
+    /// - `None` - сортировка не задана
+    pub fn order_by(&self) -> Option<String> {
+        self.order_by.clone()
+    }
+
+    /// Возвращает значение LIMIT
+    pub fn limit(&self) -> u64 {
+        self.limit
+    }
+
+    /// Возвращает значение OFFSET
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Возвращает флаг `SELECT DISTINCT`
+    pub fn distinct(&self) -> bool {
+        self.distinct
+    }
+
+    /// Собирает полный запрос `SELECT` к указанному источнику
+    /// 
+    /// `from` подставляется в запрос как есть, поэтому должен задаваться
+    /// приложением, а не браться из пользовательского ввода.
+    /// 
+    /// # Примеры
+    /// ```
+    /// # use sieve::{SieveModel, SqlDialect};
+    /// let model = SieveModel::new(&Some(1), &Some(10), &Some("artist==Queen".to_string()), &Some("title".to_string()))
+    ///     .with_distinct(&Some("true".to_string()));
+    /// let parts = model.describe_sql(SqlDialect::Postgres);
+    /// assert_eq!(
+    ///     parts.to_select("tracks"),
+    ///     r#"SELECT DISTINCT * FROM tracks WHERE "artist" = $1 ORDER BY "title" ASC LIMIT 10 OFFSET 0"#
+    /// );
+    /// 
+    /// let model = SieveModel::new(&Some(1), &Some(10), &None, &None);
+    /// let parts = model.describe_sql(SqlDialect::Postgres);
+    /// assert_eq!(parts.to_select("tracks"), "SELECT * FROM tracks LIMIT 10 OFFSET 0");
+    /// ```
+    pub fn to_select(&self, from: &str) -> String {
+        let mut sql = if self.distinct {
+            format!("SELECT DISTINCT * FROM {}", from)
+        } else {
+            format!("SELECT * FROM {}", from)
+        };
+        if let Some(where_clause) = &self.where_clause {
+            sql.push_str(&format!(" WHERE {}", where_clause));
+        }
+        if let Some(order_by) = &self.order_by {
+            sql.push_str(&format!(" ORDER BY {}", order_by));
+        }
+        sql.push_str(&format!(" LIMIT {} OFFSET {}", self.limit, self.offset));
+        sql
+    }
+}
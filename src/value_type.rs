@@ -0,0 +1,102 @@
+use std::fmt;
+use std::str::FromStr;
+use super::field_value::FieldValue;
+use super::sieve_regex::DATE_PATTERN;
+
+/// Явный тип значения фильтра, заданный суффиксом (`year==2000:int`)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ValueType {
+    /// Целое число (`:int`)
+    Int,
+
+    /// Число с плавающей точкой (`:float`)
+    Float,
+
+    /// Логическое значение (`:bool`): `true`/`false`, `1`/`0`, `yes`/`no`, `on`/`off`
+    Bool,
+
+    /// Дата или дата и время в формате ISO 8601 (`:date`)
+    Date,
+}
+
+/// Реализация методов для ValueType
+impl ValueType {
+    /// Приводит значение фильтра к типу или возвращает `None`, если это невозможно
+    ///
+    /// Дата остается строкой, так как строки ISO 8601 сравниваются лексикографически.
+    /// `NaN` и бесконечности не считаются числами.
+    ///
+    /// # Примеры
+    /// ```
+    /// # use sieve::{FieldValue, ValueType};
+    /// assert_eq!(ValueType::Int.coerce("2000"), Some(FieldValue::Int(2000)));
+    /// assert_eq!(ValueType::Int.coerce("20.5"), None);
+    /// assert_eq!(ValueType::Float.coerce("20"), Some(FieldValue::Float(20.0)));
+    /// assert_eq!(ValueType::Float.coerce("NaN"), None);
+    /// assert_eq!(ValueType::Float.coerce("inf"), None);
+    /// assert_eq!(ValueType::Bool.coerce("1"), Some(FieldValue::Bool(true)));
+    /// assert_eq!(ValueType::Bool.coerce("off"), Some(FieldValue::Bool(false)));
+    /// assert_eq!(ValueType::Date.coerce("2023-01-01"), Some(FieldValue::from("2023-01-01")));
+    /// assert_eq!(ValueType::Date.coerce("2023-01-01T10:30:00Z"), Some(FieldValue::from("2023-01-01T10:30:00Z")));
+    /// assert_eq!(ValueType::Date.coerce("yesterday"), None);
+    /// ```
+    pub fn coerce(&self, value: &str) -> Option<FieldValue> {
+        let value = value.trim();
+        match self {
+            ValueType::Int => value.parse::<i64>().ok().map(FieldValue::Int),
+            ValueType::Float => value.parse::<f64>().ok().filter(|value| value.is_finite()).map(FieldValue::Float),
+            ValueType::Bool => match value.to_lowercase().as_str() {
+                "true" | "1" | "yes" | "on" => Some(FieldValue::Bool(true)),
+                "false" | "0" | "no" | "off" => Some(FieldValue::Bool(false)),
+                _ => None,
+            },
+            ValueType::Date => DATE_PATTERN.is_match(value).then(|| FieldValue::from(value)),
+        }
+    }
+
+    /// Определяет тип значения фильтра без явной аннотации
+    ///
+    /// # Примеры
+    /// ```
+    /// # use sieve::{FieldValue, ValueType};
+    /// assert_eq!(ValueType::infer("2000"), FieldValue::Int(2000));
+    /// assert_eq!(ValueType::infer("3.5"), FieldValue::Float(3.5));
+    /// assert_eq!(ValueType::infer("true"), FieldValue::Bool(true));
+    /// assert_eq!(ValueType::infer("1"), FieldValue::Int(1));
+    /// assert_eq!(ValueType::infer("Rock"), FieldValue::from("Rock"));
+    /// assert_eq!(ValueType::infer("NaN"), FieldValue::from("NaN"));
+    /// assert_eq!(ValueType::infer("-infinity"), FieldValue::from("-infinity"));
+    /// ```
+    pub fn infer(value: &str) -> FieldValue {
+        [ValueType::Int, ValueType::Float]
+            .iter()
+            .find_map(|value_type| value_type.coerce(value))
+            .or_else(|| matches!(value, "true" | "false").then(|| FieldValue::Bool(value == "true")))
+            .unwrap_or_else(|| FieldValue::from(value))
+    }
+}
+
+impl FromStr for ValueType {
+    type Err = String;
+
+    fn from_str(value_type: &str) -> Result<Self, Self::Err> {
+        match value_type {
+            "int" => Ok(ValueType::Int),
+            "float" => Ok(ValueType::Float),
+            "bool" => Ok(ValueType::Bool),
+            "date" => Ok(ValueType::Date),
+            _ => Err(format!("Unknown value type: {}", value_type)),
+        }
+    }
+}
+
+impl fmt::Display for ValueType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValueType::Int => write!(f, "int"),
+            ValueType::Float => write!(f, "float"),
+            ValueType::Bool => write!(f, "bool"),
+            ValueType::Date => write!(f, "date"),
+        }
+    }
+}